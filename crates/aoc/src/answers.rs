@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../answers.toml");
+
+/// Confirmed answer of a day and part, for a given input.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// digest of the input, see [`digest`]
+    pub input: String,
+    pub value: String,
+}
+
+/// Registry of confirmed answers, stored as TOML.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// a missing file is an empty registry
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("invalid answers file {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|x| x.day == day && x.part == part && x.input == input)
+            .map(|x| x.value.as_str())
+    }
+
+    /// record an answer, replacing any previous one for the same day, part and input
+    pub fn insert(&mut self, answer: Answer) {
+        self.answers
+            .retain(|x| (x.day, x.part, &x.input) != (answer.day, answer.part, &answer.input));
+        self.answers.push(answer);
+        self.answers.sort();
+    }
+}
+
+/// Short and stable identifier of an input.
+pub fn digest(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .take(8)
+        .map(|x| format!("{x:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digests() {
+        assert_eq!(digest(""), "e3b0c44298fc1c14");
+        assert_eq!(digest("L68\nL30"), digest("L68\nL30"));
+        assert_ne!(digest("L68\nL30"), digest("L68\nL31"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut answers = Answers::default();
+        answers.insert(Answer {
+            day: 2,
+            part: 1,
+            input: "abc".to_string(),
+            value: "12".to_string(),
+        });
+        answers.insert(Answer {
+            day: 1,
+            part: 2,
+            input: "abc".to_string(),
+            value: "6".to_string(),
+        });
+        answers.insert(Answer {
+            day: 2,
+            part: 1,
+            input: "abc".to_string(),
+            value: "13".to_string(),
+        });
+        assert_eq!(answers.get(2, 1, "abc"), Some("13"));
+        assert_eq!(answers.get(2, 1, "def"), None);
+        let content = toml::to_string(&answers).unwrap();
+        assert_eq!(
+            content,
+            r#"[[answer]]
+day = 1
+part = 2
+input = "abc"
+value = "6"
+
+[[answer]]
+day = 2
+part = 1
+input = "abc"
+value = "13"
+"#
+        );
+        assert_eq!(toml::from_str::<Answers>(&content).unwrap(), answers);
+    }
+
+    #[test]
+    fn registry() {
+        let answers = Answers::load(Path::new(ANSWERS)).unwrap();
+        assert!(!answers.answers.is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+use std::{fmt::Display, path::Path, time::Duration, time::Instant};
+
+use aoc_2025_solution::Entry;
+use serde::{Deserialize, Serialize};
+
+use crate::{fetch::Loader, report::Table};
+
+/// Spread of the timings of a step, in nanoseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    pub fn of(mut timings: Vec<Duration>) -> Self {
+        assert!(!timings.is_empty());
+        timings.sort();
+        let nanos = |x: Duration| x.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            min: nanos(timings[0]),
+            median: nanos(timings[timings.len() / 2]),
+            max: nanos(timings[timings.len() - 1]),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.2?} / {:.2?} / {:.2?}",
+            Duration::from_nanos(self.min),
+            Duration::from_nanos(self.median),
+            Duration::from_nanos(self.max)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measure {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// parsing then solving, timed run by run, missing from older bench files
+    #[serde(default)]
+    pub total: Stats,
+}
+
+/// Time parsing and solving of `entry` separately, `runs` times each.
+pub fn measure(entry: Entry, inputs: &Loader, runs: usize) -> Result<Measure, String> {
+    let input = inputs.read(entry.day)?;
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    let mut total = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = (entry.parse)(&input).map_err(|e| e.to_string())?;
+        let parsed_at = Instant::now();
+        std::hint::black_box((entry.solve)(parsed))?;
+        let end = Instant::now();
+        parse.push(parsed_at - start);
+        solve.push(end - parsed_at);
+        total.push(end - start);
+    }
+    Ok(Measure {
+        day: entry.day,
+        part: entry.part,
+        runs: runs.max(1),
+        parse: Stats::of(parse),
+        solve: Stats::of(solve),
+        total: Stats::of(total),
+    })
+}
+
+/// Measures of a whole run, which can be saved to compare with later on.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bench {
+    #[serde(default, rename = "measure")]
+    pub measures: Vec<Measure>,
+    #[serde(skip)]
+    pub baseline: Option<Box<Bench>>,
+}
+
+impl Bench {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        toml::from_str(&content).map_err(|e| format!("invalid bench file {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+
+    fn previous(&self, measure: &Measure) -> Option<&Measure> {
+        self.baseline.as_ref().and_then(|baseline| {
+            baseline
+                .measures
+                .iter()
+                .find(|x| (x.day, x.part) == (measure.day, measure.part))
+        })
+    }
+}
+
+/// medians of parsing, solving and both, e.g. `2.00µs / 30.00µs / 32.00µs`
+fn medians(measure: &Measure) -> String {
+    [measure.parse, measure.solve, measure.total]
+        .map(|x| format!("{:.2?}", Duration::from_nanos(x.median)))
+        .join(" / ")
+}
+
+/// relative changes of the medians of parsing, solving and both, e.g. `+0.0% / -25.0% / -23.8%`
+fn changes(before: &Measure, after: &Measure) -> String {
+    [
+        change(&before.parse, &after.parse),
+        change(&before.solve, &after.solve),
+        change(&before.total, &after.total),
+    ]
+    .join(" / ")
+}
+
+/// relative change of the median, e.g. `-12.5%` when faster
+fn change(before: &Stats, after: &Stats) -> String {
+    if before.median == 0 {
+        return "n/a".to_string();
+    }
+    let ratio = after.median as f64 / before.median as f64 - 1.;
+    format!("{:+.1}%", ratio * 100.)
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .measures
+            .iter()
+            .map(|measure| {
+                let previous = self.previous(measure);
+                [
+                    format!("{:02}", measure.day),
+                    format!("{:02}", measure.part),
+                    measure.runs.to_string(),
+                    measure.parse.to_string(),
+                    measure.solve.to_string(),
+                    measure.total.to_string(),
+                    previous.map(medians).unwrap_or_default(),
+                    previous.map(|x| changes(x, measure)).unwrap_or_default(),
+                ]
+            })
+            .collect();
+        let table = Table {
+            header: [
+                "day",
+                "part",
+                "runs",
+                "parse (min / median / max)",
+                "solve (min / median / max)",
+                "total (min / median / max)",
+                "baseline (parse / solve / total)",
+                "change (parse / solve / total)",
+            ],
+            right: [false, false, true, true, true, true, true, true],
+            rows,
+        };
+        write!(f, "{table}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_2025_solution::input::{Inputs, committed};
+
+    use super::*;
+    use crate::registry::SOLUTIONS;
+
+    #[test]
+    fn stats() {
+        let stats = Stats::of([5, 1, 3, 2, 4].map(Duration::from_nanos).to_vec());
+        assert_eq!(
+            stats,
+            Stats {
+                min: 1,
+                median: 3,
+                max: 5
+            }
+        );
+    }
+
+    #[test]
+    fn measures() {
+        if committed(1).is_none() {
+            return;
+        }
+        let measured = measure(SOLUTIONS[0], &Loader::default(), 3).unwrap();
+        assert_eq!((measured.day, measured.part, measured.runs), (1, 1, 3));
+        assert!(measured.parse.min <= measured.parse.median);
+        assert!(measured.solve.median <= measured.solve.max);
+        let nowhere = Loader::from(Inputs::default().dir(Some(Path::new("nowhere"))));
+        assert!(measure(SOLUTIONS[0], &nowhere, 3).is_err());
+    }
+
+    #[test]
+    fn compare() {
+        let measure = |parse, solve| Measure {
+            day: 1,
+            part: 1,
+            runs: 3,
+            parse: Stats {
+                min: parse,
+                median: parse,
+                max: parse,
+            },
+            solve: Stats {
+                min: solve,
+                median: solve,
+                max: solve,
+            },
+            total: Stats {
+                min: parse + solve,
+                median: parse + solve,
+                max: parse + solve,
+            },
+        };
+        let bench = Bench {
+            measures: vec![measure(4_000, 30_000)],
+            baseline: Some(Box::new(Bench {
+                measures: vec![measure(2_000, 40_000)],
+                baseline: None,
+            })),
+        };
+        let content = toml::to_string(&bench).unwrap();
+        assert_eq!(
+            toml::from_str::<Bench>(&content).unwrap().measures,
+            bench.measures
+        );
+        let older = content.replace("[measure.total]", "[ignored]");
+        let older = toml::from_str::<Bench>(&older).unwrap();
+        assert_eq!(older.measures[0].total, Stats::default());
+        assert_eq!(
+            bench.to_string(),
+            "\
+day  part  runs  parse (min / median / max)   solve (min / median / max)   total (min / median / max)  baseline (parse / solve / total)  change (parse / solve / total)
+---  ----  ----  --------------------------  ---------------------------  ---------------------------  --------------------------------  ------------------------------
+01   01       3    4.00µs / 4.00µs / 4.00µs  30.00µs / 30.00µs / 30.00µs  34.00µs / 34.00µs / 34.00µs        2.00µs / 40.00µs / 42.00µs       +100.0% / -25.0% / -19.0%
+"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+
+use aoc_2025_solution::{crypt::Key, input::encrypted};
+
+/// Encrypt every `DD/input.txt` of `dir` into `DD/input.txt.enc`,
+/// returning the files written, those already up to date being left untouched.
+pub fn encrypt(dir: &Path, key: &Key) -> Result<Vec<PathBuf>, String> {
+    let mut days = std::fs::read_dir(dir)
+        .map_err(|e| format!("failed to read {}: {e}", dir.display()))?
+        .filter_map(Result::ok)
+        .map(|x| x.path().join("input.txt"))
+        .filter(|x| x.is_file())
+        .collect::<Vec<_>>();
+    days.sort();
+    let mut written = vec![];
+    for path in days {
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let target = encrypted(&path);
+        let current = std::fs::read(&target).ok();
+        if current.is_some_and(|x| key.decrypt(&x).as_ref() == Ok(&input)) {
+            continue;
+        }
+        std::fs::write(&target, key.encrypt(&input))
+            .map_err(|e| format!("failed to write {}: {e}", target.display()))?;
+        written.push(target);
+    }
+    Ok(written)
+}
+
+/// Local key, created when there is none yet.
+pub fn key(path: &Path) -> Result<(Key, bool), String> {
+    if let Some(key) = Key::load()? {
+        return Ok((key, false));
+    }
+    let key = Key::generate();
+    std::fs::write(path, key.to_hex() + "\n")
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    Ok((key, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_2025_solution::input::Inputs;
+
+    use super::*;
+
+    #[test]
+    fn inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-encrypt-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        for (day, input) in [("01", "L68\n"), ("02", "11-22\n")] {
+            std::fs::create_dir_all(dir.join(day)).unwrap();
+            std::fs::write(dir.join(day).join("input.txt"), input).unwrap();
+        }
+        let key = Key::generate();
+        assert_eq!(
+            encrypt(&dir, &key).unwrap(),
+            vec![dir.join("01/input.txt.enc"), dir.join("02/input.txt.enc")]
+        );
+        assert!(encrypt(&dir, &key).unwrap().is_empty());
+        std::fs::write(dir.join("02/input.txt"), "33-44\n").unwrap();
+        assert_eq!(
+            encrypt(&dir, &key).unwrap(),
+            vec![dir.join("02/input.txt.enc")]
+        );
+        std::fs::remove_file(dir.join("02/input.txt")).unwrap();
+        let inputs = Inputs::default().dir(Some(&dir)).key(key);
+        assert_eq!(inputs.read(2).unwrap(), "33-44\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
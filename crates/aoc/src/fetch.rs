@@ -0,0 +1,211 @@
+use std::path::{Path, PathBuf};
+
+use aoc_2025_solution::input::{InputError, Inputs};
+
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const CACHE_VAR: &str = "AOC_CACHE";
+
+pub const BASE_URL: &str = "https://adventofcode.com/2025";
+
+pub const USER_AGENT: &str = concat!("aoc-2025-runner/", env!("CARGO_PKG_VERSION"));
+
+/// Downloads inputs once, then serves them from a cache laid out like `days/`.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache: PathBuf,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<&str>, cache: &Path) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(|x| x.trim().to_string()),
+            cache: cache.to_path_buf(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or(BASE_URL.to_string());
+        let session = std::env::var(SESSION_VAR).ok();
+        let cache = std::env::var_os(CACHE_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(default_cache);
+        Self::new(&base_url, session.as_deref(), &cache)
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache.join(format!("{day:02}/input.txt"))
+    }
+
+    /// cached input of `day`, downloaded first if needed
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let path = self.path(day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(format!(
+                    "failed to read cached input {}: {e}",
+                    path.display()
+                ));
+            }
+            Err(_) => (),
+        }
+        let input = self.download(day)?;
+        let parent = path.parent().unwrap_or(&self.cache);
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+        // written aside then renamed, so that an interrupted download is never cached
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, &input)
+            .and_then(|_| std::fs::rename(&partial, &path))
+            .map_err(|e| format!("failed to cache input {}: {e}", path.display()))?;
+        Ok(input)
+    }
+
+    fn download(&self, day: u8) -> Result<String, String> {
+        let session = self.session.as_deref().ok_or(format!(
+            "no session token in {SESSION_VAR} to download the input of day {day:02}"
+        ))?;
+        let url = format!("{}/day/{day}/input", self.base_url);
+        let mut response = ureq::get(&url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| format!("failed to download {url}: {e}"))?;
+        let input = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("failed to download {url}: {e}"))?;
+        match input.is_empty() {
+            true => Err(format!("failed to download {url}: empty input")),
+            false => Ok(input),
+        }
+    }
+}
+
+/// `$XDG_CACHE_HOME/aoc-2025`, `~/.cache/aoc-2025`, else `target/inputs` in the workspace
+fn default_cache() -> PathBuf {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|x| Path::new(&x).join(".cache")))
+        .map(|x| x.join("aoc-2025"))
+        .unwrap_or_else(|| Path::new(crate::scaffold::WORKSPACE).join("target/inputs"))
+}
+
+/// Reads inputs, falling back to the fetcher when none is found.
+#[derive(Debug, Default)]
+pub struct Loader {
+    pub inputs: Inputs,
+    pub fetcher: Option<Fetcher>,
+}
+
+impl From<Inputs> for Loader {
+    fn from(inputs: Inputs) -> Self {
+        Self {
+            inputs,
+            fetcher: None,
+        }
+    }
+}
+
+/// Input which could not be loaded.
+#[derive(Debug, PartialEq)]
+pub enum LoadError {
+    /// found nowhere, nor downloaded
+    Missing(String),
+    Failed(String),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(e) | Self::Failed(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Loader {
+    pub fn load(&self, day: u8) -> Result<String, LoadError> {
+        match (self.inputs.read(day), &self.fetcher) {
+            // never there, whether or not it could be downloaded
+            (Err(e @ InputError::Missing { .. }), Some(fetcher)) => fetcher
+                .input(day)
+                .map_err(|x| LoadError::Missing(format!("{e}, and {x}"))),
+            (Err(e @ InputError::Locked(_)), Some(fetcher)) => fetcher
+                .input(day)
+                .map_err(|x| LoadError::Failed(format!("{e}, or {x}"))),
+            (Err(e @ InputError::Missing { .. }), None) => Err(LoadError::Missing(e.to_string())),
+            (input, _) => input.map_err(|e| LoadError::Failed(e.to_string())),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        self.load(day).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve;
+
+    fn cache(name: &str) -> PathBuf {
+        let cache =
+            std::env::temp_dir().join(format!("aoc-2025-fetch-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache);
+        cache
+    }
+
+    #[test]
+    fn cached() {
+        let (url, server) = serve(vec![(200, "L68\nR48\n")]);
+        let cache = cache("cached");
+        let fetcher = Fetcher::new(&url, Some("53cr3t\n"), &cache);
+        assert_eq!(fetcher.input(1), Ok("L68\nR48\n".to_string()));
+        // the server is gone after one request, so this one has to come from the cache
+        assert_eq!(fetcher.input(1), Ok("L68\nR48\n".to_string()));
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=53cr3t"));
+        assert!(cache.join("01/input.txt").exists());
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn failures() {
+        let (url, server) = serve(vec![(404, "not found")]);
+        let cache = cache("failures");
+        let fetcher = Fetcher::new(&url, Some("53cr3t"), &cache);
+        assert!(fetcher.input(12).is_err());
+        server.join().unwrap();
+        assert!(!fetcher.path(12).exists());
+        let fetcher = Fetcher::new(&url, None, &cache);
+        assert_eq!(
+            fetcher.input(12),
+            Err("no session token in AOC_SESSION to download the input of day 12".to_string())
+        );
+    }
+
+    #[test]
+    fn loader() {
+        let (url, server) = serve(vec![(200, "3-5\n\n1\n")]);
+        let cache = cache("loader");
+        let loader = Loader {
+            inputs: Inputs::default().file(Some("nowhere/05.txt")),
+            fetcher: Some(Fetcher::new(&url, Some("53cr3t"), &cache)),
+        };
+        // explicit inputs are never replaced by downloads
+        assert!(loader.read(5).is_err());
+        let loader = Loader {
+            inputs: Inputs::default(),
+            ..loader
+        };
+        assert_eq!(loader.read(25), Ok("3-5\n\n1\n".to_string()));
+        assert_eq!(server.join().unwrap().len(), 1);
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+}
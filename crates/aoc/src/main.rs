@@ -0,0 +1,337 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc_2025_solution::{Entry, input::Inputs};
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    answers::{ANSWERS, Answer, Answers, digest},
+    bench::Bench,
+    fetch::{Fetcher, Loader},
+    registry::SOLUTIONS,
+    report::{Report, Row},
+    selection::Selection,
+    submit::{ATTEMPTS, Attempts, Submitter, Verdict},
+    verify::Verification,
+};
+
+mod answers;
+mod bench;
+mod encrypt;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod registry;
+mod report;
+mod scaffold;
+mod selection;
+mod submit;
+mod verify;
+
+/// Advent of Code 2025 runner
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Inputs are looked up in this order:
+/// `--input`, `AOC_INPUT`, `--inputs`, `AOC_INPUTS`,
+/// then `days/DD/input.txt` from the current directory or the workspace root,
+/// any of them possibly encrypted as `input.txt.enc` and decrypted with the local key,
+/// and last the cache, downloading missing inputs with the token in `AOC_SESSION`.
+#[derive(Debug, Args)]
+struct InputArgs {
+    /// input file for the selected day(s), or `-` for stdin
+    #[arg(long, value_name = "PATH")]
+    input: Option<String>,
+    /// directory laid out like `days/`, i.e. `DIR/DD/input.txt`
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<PathBuf>,
+    /// never download missing inputs
+    #[arg(long)]
+    offline: bool,
+}
+
+impl InputArgs {
+    fn resolve(&self) -> Loader {
+        Loader {
+            inputs: Inputs::from_env()
+                .dir(self.inputs.as_deref())
+                .file(self.input.as_deref()),
+            fetcher: (!self.offline).then(Fetcher::from_env),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// solve the selected day(s) and print a summary table
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        #[command(flatten)]
+        inputs: InputArgs,
+    },
+    /// time parsing and solving of the selected day(s) separately
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// how many times each day is parsed and solved
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// save the measures, to be given as a baseline later on
+        #[arg(long, value_name = "PATH")]
+        save: Option<PathBuf>,
+        /// measures to compare with, e.g. from another revision or implementation
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+    },
+    /// encrypt the inputs to commit them, creating the local key if needed
+    Encrypt {
+        /// directory laid out like `days/`
+        #[arg(long, value_name = "DIR")]
+        inputs: Option<PathBuf>,
+    },
+    /// download the input of a day into the cache, unless already there
+    Fetch { day: u8 },
+    /// create the crate of a new day, registered in the workspace and this runner
+    New { day: u8 },
+    /// solve a day and part, then submit the answer unless known to be wrong
+    Submit {
+        day: u8,
+        part: u8,
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// registry of confirmed answers, where a correct one is recorded
+        #[arg(long, value_name = "PATH", default_value = ANSWERS)]
+        answers: PathBuf,
+        /// log of the previous submissions and their verdicts
+        #[arg(long, value_name = "PATH", default_value = ATTEMPTS)]
+        attempts: PathBuf,
+    },
+    /// solve every day and compare the answers with the confirmed ones
+    Verify {
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// registry of confirmed answers
+        #[arg(long, value_name = "PATH", default_value = ANSWERS)]
+        answers: PathBuf,
+        /// record missing answers, once confirmed on the website
+        #[arg(long)]
+        record: bool,
+        /// fail for days without any input, instead of skipping them
+        #[arg(long)]
+        require_inputs: bool,
+    },
+}
+
+fn workspace() -> PathBuf {
+    let root = Path::new(scaffold::WORKSPACE);
+    root.canonicalize().unwrap_or(root.to_path_buf())
+}
+
+fn run(entry: Entry, inputs: &Loader) -> Row {
+    let start = Instant::now();
+    let outcome = inputs
+        .read(entry.day)
+        .and_then(|input| entry.run(&input).map_err(|e| e.to_string()));
+    Row {
+        entry,
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    inputs: &Loader,
+    answers: &Path,
+    attempts: &Path,
+) -> Result<Verdict, String> {
+    let entry = SOLUTIONS
+        .iter()
+        .find(|x| (x.day, x.part) == (day, part))
+        .ok_or(format!("no solution for day {day:02} part {part:02}"))?;
+    let input = inputs.read(day)?;
+    let value = entry.run(&input).map_err(|e| e.to_string())?;
+    println!("{entry:?}: submitting {value}");
+    let mut registry = Answers::load(answers)?;
+    let mut log = Attempts::load(attempts)?;
+    let answer = Answer {
+        day,
+        part,
+        input: digest(&input),
+        value,
+    };
+    let verdict = Submitter::from_env().submit(&mut log, &mut registry, answer)?;
+    log.save(attempts)?;
+    if verdict == Verdict::Correct {
+        registry.save(answers)?;
+    }
+    Ok(verdict)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { selection, inputs } => {
+            let inputs = inputs.resolve();
+            let entries = match selection.select(SOLUTIONS) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let report = Report(entries.into_iter().map(|x| run(x, &inputs)).collect());
+            print!("{report}");
+            if report.failures() > 0 {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            selection,
+            inputs,
+            runs,
+            save,
+            baseline,
+        } => {
+            let inputs = inputs.resolve();
+            let baseline = match baseline.as_deref().map(Bench::load).transpose() {
+                Ok(baseline) => baseline.map(Box::new),
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let entries = match selection.select(SOLUTIONS) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut bench = Bench {
+                measures: Vec::with_capacity(entries.len()),
+                baseline,
+            };
+            for entry in entries {
+                match bench::measure(entry, &inputs, runs) {
+                    Ok(measure) => bench.measures.push(measure),
+                    Err(e) => eprintln!("error: {entry:?}: {e}"),
+                }
+            }
+            print!("{bench}");
+            if let Some(Err(e)) = save.as_deref().map(|x| bench.save(x)) {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Encrypt { inputs } => {
+            let dir = inputs.unwrap_or(workspace().join("days"));
+            let key_file = aoc_2025_solution::crypt::key_file();
+            let written = encrypt::key(&key_file).and_then(|(key, created)| {
+                if created {
+                    println!(
+                        "created key {}, keep it safe and never commit it",
+                        key_file.display()
+                    );
+                }
+                encrypt::encrypt(&dir, &key)
+            });
+            match written {
+                Ok(written) => {
+                    for path in written {
+                        println!("encrypted {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Fetch { day } => {
+            let fetcher = Fetcher::from_env();
+            if let Err(e) = fetcher.input(day) {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+            println!("{}", fetcher.path(day).display());
+        }
+        Command::New { day } => match scaffold::scaffold(&workspace(), day) {
+            Ok(scaffold) => {
+                for path in scaffold.created {
+                    println!("created {}", path.display());
+                }
+                for path in scaffold.updated {
+                    println!("updated {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Submit {
+            day,
+            part,
+            inputs,
+            answers,
+            attempts,
+        } => match submit(day, part, &inputs.resolve(), &answers, &attempts) {
+            Ok(verdict) => {
+                println!("{verdict}");
+                if verdict != Verdict::Correct {
+                    return ExitCode::FAILURE;
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Verify {
+            inputs,
+            answers: path,
+            record,
+            require_inputs,
+        } => {
+            let inputs = inputs.resolve();
+            let mut answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let verification = Verification(
+                SOLUTIONS
+                    .iter()
+                    .map(|x| verify::check(*x, &inputs, &answers))
+                    .collect(),
+            );
+            println!("{verification}");
+            if record && verification.missing() > 0 {
+                verification.record(&mut answers);
+                if let Err(e) = answers.save(&path) {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+                println!("recorded missing answers in {}", path.display());
+            }
+            if verification.failures() > 0 || (require_inputs && verification.skipped() > 0) {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
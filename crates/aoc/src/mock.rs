@@ -0,0 +1,47 @@
+//! Local stand-in for the puzzle website.
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::JoinHandle,
+};
+
+/// Serve `responses` in order, one per connection, on a local base URL.
+/// The server returns the requests it received, headers and body.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/2025", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = vec![];
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|x| x > 2) {
+                request.push(line.trim_end().to_string());
+                line.clear();
+            }
+            let length = request
+                .iter()
+                .find_map(|x| {
+                    x.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push(String::from_utf8(content).unwrap());
+            requests.push(request.join("\n"));
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+        requests
+    });
+    (url, server)
+}
@@ -0,0 +1,44 @@
+use aoc_2025_solution::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::of::<aoc_2025_01::Part1>(),
+    Entry::of::<aoc_2025_01::Part2>(),
+    Entry::of::<aoc_2025_02::Part1>(),
+    Entry::of::<aoc_2025_02::Part2>(),
+    Entry::of::<aoc_2025_03::Part1>(),
+    Entry::of::<aoc_2025_03::Part2>(),
+    Entry::of::<aoc_2025_04::Part1>(),
+    Entry::of::<aoc_2025_04::Part2>(),
+    Entry::of::<aoc_2025_05::Part1>(),
+    Entry::of::<aoc_2025_05::Part2>(),
+    Entry::of::<aoc_2025_06::Part1>(),
+    Entry::of::<aoc_2025_06::Part2>(),
+    Entry::of::<aoc_2025_07::Part1>(),
+    Entry::of::<aoc_2025_07::Part2>(),
+    Entry::of::<aoc_2025_08::Part1>(),
+    Entry::of::<aoc_2025_08::Part2>(),
+    Entry::of::<aoc_2025_09::Part1>(),
+    Entry::of::<aoc_2025_09::Part2>(),
+    Entry::of::<aoc_2025_10::Part1>(),
+    Entry::of::<aoc_2025_10::Part2>(),
+    Entry::of::<aoc_2025_11::Part1>(),
+    Entry::of::<aoc_2025_11::Part2>(),
+    Entry::of::<aoc_2025_12::Part1>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_and_unique() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!(
+                (pair[0].day, pair[0].part) < (pair[1].day, pair[1].part),
+                "{:?} should come before {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fmt::Display, time::Duration};
+
+use aoc_2025_solution::Entry;
+
+pub struct Row {
+    pub entry: Entry,
+    pub outcome: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+pub struct Report(pub Vec<Row>);
+
+impl Report {
+    pub fn failures(&self) -> usize {
+        self.0.iter().filter(|x| x.outcome.is_err()).count()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .0
+            .iter()
+            .map(|row| {
+                [
+                    format!("{:02}", row.entry.day),
+                    format!("{:02}", row.entry.part),
+                    row.entry.label.to_string(),
+                    match &row.outcome {
+                        Ok(answer) => answer.clone(),
+                        Err(e) => format!("error: {e}"),
+                    },
+                    format!("{:.2?}", row.elapsed),
+                ]
+            })
+            .collect();
+        let table = Table {
+            header: ["day", "part", "label", "answer", "time"],
+            right: [false, false, false, true, true],
+            rows,
+        };
+        write!(f, "{table}")
+    }
+}
+
+/// Plain text table, with columns aligned to the right when flagged so.
+pub struct Table<const N: usize> {
+    pub header: [&'static str; N],
+    pub right: [bool; N],
+    pub rows: Vec<[String; N]>,
+}
+
+impl<const N: usize> Display for Table<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut widths = self.header.map(str::len);
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let header = self.header.map(String::from);
+        let separator = widths.map(|x| "-".repeat(x));
+        for row in [header, separator].iter().chain(self.rows.iter()) {
+            let line = row
+                .iter()
+                .zip(widths.iter().zip(self.right.iter()))
+                .map(|(cell, (&width, &right))| match right {
+                    true => format!("{cell:>width$}"),
+                    false => format!("{cell:<width$}"),
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SOLUTIONS;
+
+    #[test]
+    fn table() {
+        let report = Report(vec![
+            Row {
+                entry: SOLUTIONS[0],
+                outcome: Ok("1076".to_string()),
+                elapsed: Duration::from_micros(1500),
+            },
+            Row {
+                entry: SOLUTIONS[1],
+                outcome: Err("invalid rotation (X1)".to_string()),
+                elapsed: Duration::from_micros(20),
+            },
+        ]);
+        assert_eq!(report.failures(), 1);
+        assert_eq!(
+            report.to_string(),
+            "\
+day  part  label                              answer     time
+---  ----  -----------  ----------------------------  -------
+01   01    password is                          1076   1.50ms
+01   02    password is  error: invalid rotation (X1)  20.00µs
+"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use std::path::{Path, PathBuf};
+
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+/// rewrite of a file registering a new day
+type Register = fn(&str, u8) -> Result<String, String>;
+
+/// Files written or updated when scaffolding a day.
+#[derive(Debug, Default, PartialEq)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Create the `days/DD` crate for `day`, with both of its parts,
+/// register it in the workspace and wire it into the runner.
+pub fn scaffold(root: &Path, day: u8) -> Result<Scaffold, String> {
+    if !(1..=12).contains(&day) {
+        return Err(format!("no such puzzle: day {day}, expected days 1 to 12"));
+    }
+    let dir = root.join(format!("days/{day:02}"));
+    let manifest_path = dir.join("Cargo.toml");
+    if manifest_path.exists() {
+        return Err(format!("{} already exists", manifest_path.display()));
+    }
+    let mut scaffold = Scaffold::default();
+    let example = dir.join("example.txt");
+    let files = [
+        (manifest_path, manifest(day)),
+        (dir.join("src/main.rs"), main(day)),
+        (dir.join("src/lib.rs"), lib(day)),
+    ];
+    for (path, content) in files {
+        write(&path, &content)?;
+        scaffold.created.push(path);
+    }
+    if !example.exists() {
+        write(&example, "")?;
+        scaffold.created.push(example);
+    }
+    let updates: [(PathBuf, Register); 3] = [
+        (root.join("Cargo.toml"), register_member),
+        (root.join("crates/aoc/Cargo.toml"), register_dependency),
+        (root.join("crates/aoc/src/registry.rs"), register_solution),
+    ];
+    for (path, update) in updates {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let content = update(&content, day)
+            .map_err(|e| format!("failed to update {}: {e}", path.display()))?;
+        write(&path, &content)?;
+        scaffold.updated.push(path);
+    }
+    Ok(scaffold)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+    }
+    std::fs::write(path, content).map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+fn manifest(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "aoc-2025-{day:02}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-2025-solution.workspace = true
+
+[dev-dependencies]
+test-case = "3"
+"#
+    )
+}
+
+fn main(day: u8) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use aoc_2025_{day:02}::{{Part1, Part2}};
+use aoc_2025_solution::Entry;
+
+fn main() -> ExitCode {{
+    aoc_2025_solution::main(&[Entry::of::<Part1>(), Entry::of::<Part2>()])
+}}
+"#
+    )
+}
+
+fn lib(day: u8) -> String {
+    format!(
+        r#"use std::str::FromStr;
+
+use aoc_2025_solution::{{ParseError, Solution, parse::lines}};
+
+pub struct Puzzle(Vec<String>);
+
+impl Puzzle {{
+    pub fn part1(&self) -> usize {{
+        self.0.len()
+    }}
+
+    pub fn part2(&self) -> usize {{
+        self.0.len()
+    }}
+}}
+
+impl FromStr for Puzzle {{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        Ok(Self(lines(s, |line| Ok(line.to_string()))?))
+    }}
+}}
+
+pub struct Part1;
+
+impl Solution for Part1 {{
+    const DAY: u8 = {day};
+    const PART: u8 = 1;
+    const LABEL: &'static str = "answer";
+
+    type Input = Puzzle;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Puzzle::from_str(input)
+    }}
+
+    fn solve(input: Self::Input) -> Self::Answer {{
+        input.part1()
+    }}
+}}
+
+pub struct Part2;
+
+impl Solution for Part2 {{
+    const DAY: u8 = {day};
+    const PART: u8 = 2;
+    const LABEL: &'static str = "answer";
+
+    type Input = Puzzle;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Puzzle::from_str(input)
+    }}
+
+    fn solve(input: Self::Input) -> Self::Answer {{
+        input.part2()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test_case(EXAMPLE, 0, 0 ; "example")]
+    fn example(given: &str, part1: usize, part2: usize) {{
+        assert_eq!(Part1::run(given), Ok(part1));
+        assert_eq!(Part2::run(given), Ok(part2));
+    }}
+}}
+"#
+    )
+}
+
+/// add `days/DD` to the workspace members
+fn register_member(content: &str, day: u8) -> Result<String, String> {
+    let line = format!("    \"days/{day:02}\",");
+    insert_sorted(content, &line, |x| x.starts_with("    \"days/"))
+}
+
+/// add `aoc-2025-DD` to the runner dependencies
+fn register_dependency(content: &str, day: u8) -> Result<String, String> {
+    let line = format!("aoc-2025-{day:02} = {{ path = \"../../days/{day:02}\" }}");
+    insert_sorted(content, &line, |x| {
+        x.starts_with("aoc-2025-") && x.contains("path = \"../../days/")
+    })
+}
+
+/// add both parts of `aoc-2025-DD` to the runner registry
+fn register_solution(content: &str, day: u8) -> Result<String, String> {
+    let mut content = content.to_string();
+    for part in 1..=2 {
+        let line = format!("    Entry::of::<aoc_2025_{day:02}::Part{part}>(),");
+        content = insert_sorted(&content, &line, |x| {
+            x.starts_with("    Entry::of::<aoc_2025_")
+        })?;
+    }
+    Ok(content)
+}
+
+/// insert `line` among the consecutive lines matching `block`, keeping them sorted
+fn insert_sorted(
+    content: &str,
+    line: &str,
+    block: impl Fn(&str) -> bool,
+) -> Result<String, String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        return Ok(content.to_string());
+    }
+    let first = lines
+        .iter()
+        .position(|x| block(x))
+        .ok_or("no place found to register the day")?;
+    let count = lines[first..].iter().take_while(|x| block(x)).count();
+    let at = first + lines[first..first + count].partition_point(|x| *x < line);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+members = [
+    "crates/aoc",
+    "crates/solution",
+    "days/01",
+    "days/09",
+]
+resolver = "2"
+"#;
+
+    #[test]
+    fn members() {
+        assert_eq!(
+            register_member(MANIFEST, 7).unwrap(),
+            r#"[workspace]
+members = [
+    "crates/aoc",
+    "crates/solution",
+    "days/01",
+    "days/07",
+    "days/09",
+]
+resolver = "2"
+"#
+        );
+        let manifest = register_member(MANIFEST, 12).unwrap();
+        assert!(manifest.contains("    \"days/09\",\n    \"days/12\",\n]"));
+        assert_eq!(register_member(&manifest, 12).unwrap(), manifest);
+    }
+
+    #[test]
+    fn registry() {
+        let registry = "pub const SOLUTIONS: &[Entry] = &[
+    Entry::of::<aoc_2025_01::Part1>(),
+    Entry::of::<aoc_2025_01::Part2>(),
+    Entry::of::<aoc_2025_09::Part1>(),
+];
+";
+        assert_eq!(
+            register_solution(registry, 7).unwrap(),
+            "pub const SOLUTIONS: &[Entry] = &[
+    Entry::of::<aoc_2025_01::Part1>(),
+    Entry::of::<aoc_2025_01::Part2>(),
+    Entry::of::<aoc_2025_07::Part1>(),
+    Entry::of::<aoc_2025_07::Part2>(),
+    Entry::of::<aoc_2025_09::Part1>(),
+];
+"
+        );
+        let dependencies = "[dependencies]
+clap = \"4\"
+aoc-2025-01 = { path = \"../../days/01\" }
+";
+        assert_eq!(
+            register_dependency(dependencies, 12).unwrap(),
+            "[dependencies]
+clap = \"4\"
+aoc-2025-01 = { path = \"../../days/01\" }
+aoc-2025-12 = { path = \"../../days/12\" }
+"
+        );
+    }
+
+    #[test]
+    fn files() {
+        let root = std::env::temp_dir().join(format!("aoc-2025-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        write(&root.join("Cargo.toml"), MANIFEST).unwrap();
+        write(
+            &root.join("crates/aoc/Cargo.toml"),
+            "[dependencies]\naoc-2025-01 = { path = \"../../days/01\" }\n",
+        )
+        .unwrap();
+        write(
+            &root.join("crates/aoc/src/registry.rs"),
+            "&[\n    Entry::of::<aoc_2025_01::Part1>(),\n];\n",
+        )
+        .unwrap();
+        // an input may be there before the crate
+        write(&root.join("days/07/input.txt"), "1").unwrap();
+        let scaffold = scaffold(&root, 7).unwrap();
+        assert_eq!(scaffold.created.len(), 4);
+        assert_eq!(scaffold.updated.len(), 3);
+        let lib = std::fs::read_to_string(root.join("days/07/src/lib.rs")).unwrap();
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(lib.contains("impl Solution for Part2"));
+        assert!(root.join("days/07/example.txt").exists());
+        assert!(super::scaffold(&root, 7).is_err());
+        assert!(super::scaffold(&root, 13).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
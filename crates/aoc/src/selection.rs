@@ -0,0 +1,81 @@
+use aoc_2025_solution::Entry;
+use clap::Args;
+
+#[derive(Debug, Default, Args)]
+#[group(required = true, multiple = true)]
+pub struct Selection {
+    /// day to run, e.g. `aoc run 4`
+    #[arg(value_name = "DAY")]
+    day: Option<u8>,
+    /// part to run, e.g. `aoc run 4 2`
+    #[arg(value_name = "PART", requires = "day")]
+    part: Option<u8>,
+    /// day to run, all of its parts unless `--part` is given
+    #[arg(long = "day", value_name = "DAY", conflicts_with = "day")]
+    day_flag: Option<u8>,
+    /// part to run, for the selected day
+    #[arg(long = "part", value_name = "PART", conflicts_with = "part")]
+    part_flag: Option<u8>,
+    /// run every available day and part
+    #[arg(long, conflicts_with_all = ["day", "day_flag"])]
+    all: bool,
+}
+
+impl Selection {
+    #[cfg(test)]
+    pub fn all() -> Self {
+        Self {
+            all: true,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(test)]
+    pub fn day(day: u8, part: Option<u8>) -> Self {
+        Self {
+            day: Some(day),
+            part,
+            ..Default::default()
+        }
+    }
+
+    pub fn matches(&self, entry: &Entry) -> bool {
+        if self.all {
+            return true;
+        }
+        let day = self.day.or(self.day_flag);
+        let part = self.part.or(self.part_flag);
+        day == Some(entry.day) && part.is_none_or(|x| x == entry.part)
+    }
+
+    pub fn select(&self, entries: &[Entry]) -> Result<Vec<Entry>, String> {
+        let selected = entries
+            .iter()
+            .filter(|x| self.matches(x))
+            .copied()
+            .collect::<Vec<_>>();
+        if selected.is_empty() {
+            return Err("no solution matches the selection".to_string());
+        }
+        Ok(selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SOLUTIONS;
+
+    #[test]
+    fn select() {
+        assert_eq!(
+            Selection::all().select(SOLUTIONS).unwrap().len(),
+            SOLUTIONS.len()
+        );
+        assert_eq!(Selection::day(5, None).select(SOLUTIONS).unwrap().len(), 2);
+        let selected = Selection::day(4, Some(2)).select(SOLUTIONS).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!((selected[0].day, selected[0].part), (4, 2));
+        assert!(Selection::day(25, None).select(SOLUTIONS).is_err());
+    }
+}
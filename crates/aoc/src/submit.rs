@@ -0,0 +1,301 @@
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::{Answer, Answers},
+    fetch::{BASE_URL, BASE_URL_VAR, SESSION_VAR},
+};
+
+pub const ATTEMPTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../attempts.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without any hint
+    Wrong,
+    RateLimited,
+}
+
+impl Verdict {
+    /// verdict found in the page answering a submission
+    fn of(page: &str) -> Option<Self> {
+        [
+            ("That's the right answer", Self::Correct),
+            ("You gave an answer too recently", Self::RateLimited),
+            ("your answer is too high", Self::TooHigh),
+            ("your answer is too low", Self::TooLow),
+            ("That's not the right answer", Self::Wrong),
+        ]
+        .into_iter()
+        .find(|(text, _)| page.contains(text))
+        .map(|(_, verdict)| verdict)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited => write!(f, "rate-limited, try again later"),
+        }
+    }
+}
+
+/// Answer submitted for a day and part, and the verdict it got.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    /// digest of the input, see [`crate::answers::digest`]
+    pub input: String,
+    pub value: String,
+    pub verdict: Verdict,
+    /// seconds since the unix epoch
+    pub at: u64,
+}
+
+/// Log of every submission, stored as TOML.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Attempts {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    /// a missing file is an empty log
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("invalid attempts file {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+
+    pub fn push(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// why `value` must not be submitted, given the previous attempts
+    pub fn refusal(&self, day: u8, part: u8, input: &str, value: &str) -> Option<String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|x| x.day == day && x.part == part && x.input == input)
+            .collect::<Vec<_>>();
+        if let Some(correct) = attempts.iter().find(|x| x.verdict == Verdict::Correct) {
+            return Some(format!("already solved, with {}", correct.value));
+        }
+        if let Some(known) = attempts
+            .iter()
+            .find(|x| x.value == value && x.verdict != Verdict::RateLimited)
+        {
+            return Some(format!("{value} was already submitted, {}", known.verdict));
+        }
+        let number = value.parse::<i128>().ok()?;
+        let bound = |verdict| {
+            attempts
+                .iter()
+                .filter(move |x| x.verdict == verdict)
+                .filter_map(|x| x.value.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|x| number >= *x) {
+            return Some(format!("{value} is not below {high}, known too high"));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|x| number <= *x) {
+            return Some(format!("{value} is not above {low}, known too low"));
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(|x| x.trim().to_string()),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or(BASE_URL.to_string());
+        let session = std::env::var(SESSION_VAR).ok();
+        Self::new(&base_url, session.as_deref())
+    }
+
+    /// Submit `value` unless refused by the previous `attempts`, then log the attempt,
+    /// and record the answer in `answers` when correct.
+    pub fn submit(
+        &self,
+        attempts: &mut Attempts,
+        answers: &mut Answers,
+        answer: Answer,
+    ) -> Result<Verdict, String> {
+        let Answer {
+            day,
+            part,
+            input,
+            value,
+        } = &answer;
+        if let Some(refusal) = attempts.refusal(*day, *part, input, value) {
+            return Err(format!("not submitted: {refusal}"));
+        }
+        let verdict = self.post(*day, *part, value)?;
+        attempts.push(Attempt {
+            day: *day,
+            part: *part,
+            input: input.clone(),
+            value: value.clone(),
+            verdict,
+            at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+        });
+        if verdict == Verdict::Correct {
+            answers.insert(answer);
+        }
+        Ok(verdict)
+    }
+
+    fn post(&self, day: u8, part: u8, value: &str) -> Result<Verdict, String> {
+        let session = self.session.as_deref().ok_or(format!(
+            "no session token in {SESSION_VAR} to submit the answer of day {day:02}"
+        ))?;
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let part = part.to_string();
+        let mut response = ureq::post(&url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", crate::fetch::USER_AGENT)
+            .send_form([("level", part.as_str()), ("answer", value)])
+            .map_err(|e| format!("failed to submit to {url}: {e}"))?;
+        let page = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("failed to submit to {url}: {e}"))?;
+        Verdict::of(&page).ok_or(format!("unexpected response from {url}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::serve;
+
+    fn answer(value: &str) -> Answer {
+        Answer {
+            day: 1,
+            part: 2,
+            input: "abc".to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let mut attempts = Attempts::default();
+        for (value, verdict) in [
+            ("100", Verdict::TooHigh),
+            ("200", Verdict::TooHigh),
+            ("10", Verdict::TooLow),
+            ("50", Verdict::Wrong),
+            ("60", Verdict::RateLimited),
+        ] {
+            let Answer {
+                day,
+                part,
+                input,
+                value,
+            } = answer(value);
+            attempts.push(Attempt {
+                day,
+                part,
+                input,
+                value,
+                verdict,
+                at: 0,
+            });
+        }
+        let refusal = |x| attempts.refusal(1, 2, "abc", x);
+        assert_eq!(
+            refusal("150"),
+            Some("150 is not below 100, known too high".to_string())
+        );
+        assert_eq!(
+            refusal("10"),
+            Some("10 was already submitted, too low".to_string())
+        );
+        assert_eq!(
+            refusal("-3"),
+            Some("-3 is not above 10, known too low".to_string())
+        );
+        assert!(refusal("50").is_some());
+        assert_eq!(refusal("60"), None);
+        assert_eq!(refusal("99"), None);
+        assert_eq!(attempts.refusal(1, 2, "def", "150"), None);
+        assert_eq!(attempts.refusal(1, 1, "abc", "150"), None);
+    }
+
+    #[test]
+    fn submissions() {
+        let (url, server) = serve(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>",
+            ),
+            (
+                200,
+                "<p>You gave an answer too recently; you have 42s left to wait.</p>",
+            ),
+            (
+                200,
+                "<p>That's the right answer! You are one gold star closer.</p>",
+            ),
+        ]);
+        let submitter = Submitter::new(&url, Some("53cr3t"));
+        let mut attempts = Attempts::default();
+        let mut answers = Answers::default();
+        let mut submit = |x| submitter.submit(&mut attempts, &mut answers, answer(x));
+        assert_eq!(submit("6"), Ok(Verdict::TooLow));
+        assert_eq!(
+            submit("5"),
+            Err("not submitted: 5 is not above 6, known too low".to_string())
+        );
+        assert_eq!(submit("6379"), Ok(Verdict::RateLimited));
+        assert_eq!(submit("6379"), Ok(Verdict::Correct));
+        assert_eq!(
+            submit("6379"),
+            Err("not submitted: already solved, with 6379".to_string())
+        );
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(requests[0].contains("session=53cr3t"));
+        assert!(requests[0].ends_with("level=2&answer=6"));
+        assert_eq!(answers.get(1, 2, "abc"), Some("6379"));
+        let content = toml::to_string(&attempts).unwrap();
+        assert!(content.contains("verdict = \"too-low\""));
+        assert_eq!(toml::from_str::<Attempts>(&content).unwrap(), attempts);
+    }
+}
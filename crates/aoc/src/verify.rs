@@ -0,0 +1,208 @@
+use std::fmt::Display;
+
+use aoc_2025_solution::Entry;
+
+use crate::{
+    answers::{Answer, Answers, digest},
+    fetch::{LoadError, Loader},
+    report::Table,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Confirmed,
+    Mismatch {
+        expected: String,
+    },
+    Missing,
+    /// no input to solve, which only fails the run when inputs are required
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub entry: Entry,
+    /// digest of the input, when it could be read
+    pub input: Option<String>,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+pub fn check(entry: Entry, inputs: &Loader, answers: &Answers) -> Check {
+    let input = match inputs.load(entry.day) {
+        Ok(input) => input,
+        Err(e) => {
+            return Check {
+                entry,
+                input: None,
+                answer: None,
+                status: match e {
+                    LoadError::Missing(e) => Status::Skipped(e),
+                    LoadError::Failed(e) => Status::Failed(e),
+                },
+            };
+        }
+    };
+    let digest = digest(&input);
+    let expected = answers.get(entry.day, entry.part, &digest);
+    let (answer, status) = match (entry.run(&input), expected) {
+        (Err(e), _) => (None, Status::Failed(e.to_string())),
+        (Ok(answer), None) => (Some(answer), Status::Missing),
+        (Ok(answer), Some(expected)) if answer == expected => (Some(answer), Status::Confirmed),
+        (Ok(answer), Some(expected)) => (
+            Some(answer),
+            Status::Mismatch {
+                expected: expected.to_string(),
+            },
+        ),
+    };
+    Check {
+        entry,
+        input: Some(digest),
+        answer,
+        status,
+    }
+}
+
+pub struct Verification(pub Vec<Check>);
+
+impl Verification {
+    /// mismatching answers and solvers which failed
+    pub fn failures(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|x| matches!(x.status, Status::Mismatch { .. } | Status::Failed(_)))
+            .count()
+    }
+
+    pub fn missing(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|x| x.status == Status::Missing)
+            .count()
+    }
+
+    /// solvers without any input
+    pub fn skipped(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|x| matches!(x.status, Status::Skipped(_)))
+            .count()
+    }
+
+    pub fn record(&self, answers: &mut Answers) {
+        for check in self.0.iter().filter(|x| x.status == Status::Missing) {
+            if let (Some(input), Some(value)) = (&check.input, &check.answer) {
+                answers.insert(Answer {
+                    day: check.entry.day,
+                    part: check.entry.part,
+                    input: input.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .0
+            .iter()
+            .map(|check| {
+                [
+                    format!("{:02}", check.entry.day),
+                    format!("{:02}", check.entry.part),
+                    check.input.clone().unwrap_or_default(),
+                    check.answer.clone().unwrap_or_default(),
+                    match &check.status {
+                        Status::Confirmed => "ok".to_string(),
+                        Status::Mismatch { expected } => format!("MISMATCH, expected {expected}"),
+                        Status::Missing => "missing".to_string(),
+                        Status::Skipped(e) => format!("skipped: {e}"),
+                        Status::Failed(e) => format!("error: {e}"),
+                    },
+                ]
+            })
+            .collect();
+        let table = Table {
+            header: ["day", "part", "input", "answer", "status"],
+            right: [false, false, false, true, false],
+            rows,
+        };
+        write!(f, "{table}")?;
+        write!(
+            f,
+            "{} confirmed, {} failed, {} missing, {} skipped",
+            self.0.len() - self.failures() - self.missing() - self.skipped(),
+            self.failures(),
+            self.missing(),
+            self.skipped()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_2025_solution::input::{Inputs, committed};
+
+    use super::*;
+    use crate::{fetch::Fetcher, registry::SOLUTIONS};
+
+    #[test]
+    fn statuses() {
+        let Some(input) = committed(1) else {
+            return;
+        };
+        let inputs = Loader::default();
+        let digest = digest(&input);
+        let mut answers = Answers::default();
+        answers.insert(Answer {
+            day: 1,
+            part: 1,
+            input: digest.clone(),
+            value: "1076".to_string(),
+        });
+        answers.insert(Answer {
+            day: 1,
+            part: 2,
+            input: digest.clone(),
+            value: "42".to_string(),
+        });
+        let verification = Verification(
+            [SOLUTIONS[0], SOLUTIONS[1], SOLUTIONS[2]]
+                .into_iter()
+                .map(|x| check(x, &inputs, &answers))
+                .collect(),
+        );
+        assert_eq!(verification.0[0].status, Status::Confirmed);
+        assert_eq!(
+            verification.0[1].status,
+            Status::Mismatch {
+                expected: "42".to_string()
+            }
+        );
+        assert_eq!(verification.0[2].status, Status::Missing);
+        assert_eq!(verification.failures(), 1);
+        assert_eq!(verification.missing(), 1);
+    }
+
+    #[test]
+    fn skipped() {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-verify-{}", std::process::id()));
+        let offline = Loader::from(Inputs::default().dir(Some(&dir)));
+        // without any session to download it either
+        let online = Loader {
+            inputs: Inputs::default().dir(Some(&dir)),
+            fetcher: Some(Fetcher::new("http://localhost:1", None, &dir.join("cache"))),
+        };
+        for inputs in [offline, online] {
+            let verification =
+                Verification(vec![check(SOLUTIONS[0], &inputs, &Answers::default())]);
+            assert!(matches!(verification.0[0].status, Status::Skipped(_)));
+            assert_eq!(verification.failures(), 0);
+            assert_eq!(verification.skipped(), 1);
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use aoc_2025_solution::{
+    ParseError,
+    parse::{chars, lines},
+};
+
+/// Cell of a grid drawn with one char each.
+pub trait Cell: Sized {
+    /// chars accepted, as told in parse errors, e.g. "`.` or `#`"
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    const EXPECTED: &'static str = "a char";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Position in a grid, ordered row by row.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub y: usize,
+    pub x: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    fn offset(&self, (dx, dy): (isize, isize)) -> Option<Self> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+const SIDES: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangle of cells, stored row after row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// grid of `width` columns, filled with `cells` row after row
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells fill whole rows");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// points next to `point` on its four sides, within the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        SIDES
+            .iter()
+            .filter_map(move |x| point.offset(*x))
+            .filter(|x| self.contains(*x))
+    }
+
+    /// points next to `point` on its sides and corners, within the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        AROUND
+            .iter()
+            .filter_map(move |x| point.offset(*x))
+            .filter(|x| self.contains(*x))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// every cell with its point, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// grid of the same size, with each cell mapped along its point
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.iter().map(|(p, x)| f(p, x)).collect())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} out of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} out of a {width}x{height} grid"))
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    /// one row per line, each line as long as the first one
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let rows = lines(s, |line| {
+            let row = chars(line, T::EXPECTED, T::from_char)?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                let expected = format!("a row of {width} cells");
+                return Err(ParseError::at(line, row.len().min(width) + 1, expected));
+            }
+            Ok(row)
+        })?;
+        Ok(Self::new(
+            width.unwrap_or(0),
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    /// one row per line, parsed back as the same grid
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl Cell for Tile {
+        const EXPECTED: &'static str = "`.` or `#`";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Tile::Open),
+                '#' => Some(Tile::Wall),
+                _ => None,
+            }
+        }
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    const MAP: &str = "#..\n.#.\n..#\n#.#";
+
+    #[test]
+    fn round_trip() {
+        let grid = Grid::<Tile>::from_str(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(Grid::<Tile>::from_str("").unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = Grid::<Tile>::from_str(MAP).unwrap();
+        assert_eq!(grid[Point::new(1, 1)], Tile::Wall);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 4)), None);
+        grid[Point::new(1, 1)] = Tile::Open;
+        assert_eq!(grid.to_string(), "#..\n...\n..#\n#.#");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::filled(3, 3, '.');
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(2, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 2)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::<char>::from_str("abc\ndef").unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        let column = grid.column(2).unwrap().collect::<String>();
+        assert_eq!(column, "cf");
+        assert!(grid.column(3).is_none());
+        let columns = grid
+            .columns()
+            .map(|x| x.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        let marked = grid.map(|p, c| if p.x == p.y { '*' } else { *c });
+        assert_eq!(marked.to_string(), "*bc\nd*f");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Grid::<Tile>::from_str("#..\n.x.").err(),
+            Some(ParseError::new(2, "x", "`.` or `#`").on_line(2))
+        );
+        assert_eq!(
+            Grid::<Tile>::from_str("#..\n.#..").err(),
+            Some(ParseError::new(4, ".", "a row of 3 cells").on_line(2))
+        );
+        assert_eq!(
+            Grid::<Tile>::from_str("#..\n.#").err(),
+            Some(ParseError::new(3, "", "a row of 3 cells").on_line(2))
+        );
+    }
+}
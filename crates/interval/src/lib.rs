@@ -0,0 +1,232 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use aoc_2025_solution::{
+    ParseError,
+    parse::{list, range},
+};
+
+/// Inclusive range of integers, never empty.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    start: usize,
+    end: usize,
+}
+
+impl Interval {
+    /// `start..=end`, if `start` does not come after `end`
+    pub fn new(start: usize, end: usize) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// count of integers, up to `usize::MAX + 1` for the full range
+    pub fn len(&self) -> u128 {
+        (self.end - self.start) as u128 + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn iter(&self) -> RangeInclusive<usize> {
+        self.start..=self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// whether both hold a common integer or are next to each other
+    fn touches(&self, other: &Self) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+}
+
+impl From<Interval> for RangeInclusive<usize> {
+    fn from(value: Interval) -> Self {
+        value.iter()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    /// `start-end`, both included
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = range(s)?;
+        Self::new(start, end)
+            .ok_or_else(|| ParseError::new(1, s, "a range with its start before its end"))
+    }
+}
+
+/// `start-end` intervals separated by `separator`, which may span lines
+pub fn parse_list(s: &str, separator: char) -> Result<Vec<Interval>, ParseError> {
+    list(s, separator, Interval::from_str)
+}
+
+/// Set of integers, as sorted intervals neither overlapping nor next to each other.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet(Vec<Interval>);
+
+impl IntervalSet {
+    pub fn contains(&self, value: usize) -> bool {
+        let i = self.0.partition_point(|x| x.end < value);
+        self.0.get(i).is_some_and(|x| x.contains(value))
+    }
+
+    /// count of integers, which cannot overflow
+    pub fn len(&self) -> u128 {
+        self.0.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.0
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.0.iter().chain(other.0.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.0.get(i), other.0.get(j)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self(intervals)
+    }
+
+    /// integers of `self` which are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for a in self.0.iter() {
+            let mut start = Some(a.start);
+            while let (Some(from), Some(b)) = (start, other.0.get(j)) {
+                if b.start > a.end {
+                    break;
+                }
+                if b.end >= from {
+                    intervals.extend(b.start.checked_sub(1).and_then(|x| Interval::new(from, x)));
+                    start = b.end.checked_add(1);
+                }
+                if b.end > a.end {
+                    break;
+                }
+                j += 1;
+            }
+            intervals.extend(start.and_then(|x| Interval::new(x, a.end)));
+        }
+        Self(intervals)
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    /// sorted, then merged wherever they overlap or touch
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted = iter.into_iter().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if last.touches(&interval) => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(usize, usize)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|(a, b)| Interval::new(*a, *b).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn interval() {
+        let interval = Interval::from_str("3-5").unwrap();
+        assert_eq!(interval.len(), 3);
+        assert!(interval.contains(3) && interval.contains(5) && !interval.contains(6));
+        assert_eq!(interval.to_string(), "3-5");
+        assert_eq!(
+            Interval::new(0, usize::MAX).unwrap().len(),
+            1 << usize::BITS
+        );
+        assert_eq!(Interval::new(6, 5), None);
+    }
+
+    #[test]
+    fn merge() {
+        let merged = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6), (30, 30)]);
+        assert_eq!(merged, set(&[(3, 6), (10, 20), (30, 30)]));
+        assert_eq!(merged.len(), 16);
+        assert!(merged.contains(6) && merged.contains(15) && merged.contains(30));
+        assert!(!merged.contains(2) && !merged.contains(7) && !merged.contains(21));
+        assert_eq!(set(&[(0, usize::MAX), (5, 5)]).len(), 1 << usize::BITS);
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 24), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 24), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (25, 27)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (31, 40)]));
+        let full = set(&[(0, usize::MAX)]);
+        assert_eq!(full.difference(&a), set(&[(11, 19), (31, usize::MAX)]));
+        assert!(a.difference(&full).is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_list("11-22,95115", ',').err(),
+            Some(ParseError::new(7, "95115", "a range (start-end)"))
+        );
+        assert_eq!(
+            parse_list("11-22,95-1x5", ',').err(),
+            Some(ParseError::new(10, "1x5", "a number"))
+        );
+        assert_eq!(
+            parse_list("3-5\n10-1a", '\n').err(),
+            Some(ParseError::new(4, "1a", "a number").on_line(2))
+        );
+        assert_eq!(
+            parse_list("3-5\n7-2", '\n').err(),
+            Some(ParseError::new(1, "7-2", "a range with its start before its end").on_line(2))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Encryption of the committed puzzle inputs.
+//!
+//! Inputs are stored as `input.txt.enc`, the nonce followed by the ChaCha20-Poly1305 ciphertext,
+//! with a key held locally, either as hexadecimal in the `AOC_KEY` environment variable
+//! or in the `.aoc-key` file at the workspace root, which is never committed.
+//!
+//! The nonce is derived from the input itself, so that encrypting the same input
+//! twice gives the same file and does not show up as a change.
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::{
+    ChaCha20Poly1305, KeyInit, Nonce,
+    aead::{Aead, OsRng},
+};
+use sha2::{Digest, Sha256};
+
+pub const KEY_VAR: &str = "AOC_KEY";
+pub const EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 12;
+
+pub fn key_file() -> PathBuf {
+    let root = Path::new(crate::input::WORKSPACE);
+    root.canonicalize()
+        .unwrap_or(root.to_path_buf())
+        .join(".aoc-key")
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Key([u8; 32]);
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        let invalid = || {
+            format!(
+                "invalid key, expected 64 hexadecimal digits, found {} characters",
+                hex.len()
+            )
+        };
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|x| format!("{x:02x}")).collect()
+    }
+
+    /// key from `AOC_KEY`, else from the key file, if any
+    pub fn load() -> Result<Option<Self>, String> {
+        if let Ok(hex) = std::env::var(KEY_VAR) {
+            return Self::from_hex(&hex).map(Some);
+        }
+        let path = key_file();
+        match std::fs::read_to_string(&path) {
+            Ok(hex) => Self::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("{e} in {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn encrypt(&self, input: &str) -> Vec<u8> {
+        let nonce = Sha256::new()
+            .chain_update(self.0)
+            .chain_update(input.as_bytes())
+            .finalize();
+        let nonce = Nonce::from_slice(&nonce[..NONCE_LEN]);
+        let ciphertext = ChaCha20Poly1305::new(&self.0.into())
+            .encrypt(nonce, input.as_bytes())
+            .expect("input too large to be encrypted");
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String, String> {
+        if data.len() < NONCE_LEN {
+            return Err("truncated data".to_string());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let input = ChaCha20Poly1305::new(&self.0.into())
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "wrong key or corrupted data".to_string())?;
+        String::from_utf8(input).map_err(|_| "decrypted data is not text".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let key = Key::generate();
+        let data = key.encrypt("L68\nL30\n");
+        assert_eq!(key.decrypt(&data), Ok("L68\nL30\n".to_string()));
+        assert_eq!(key.encrypt("L68\nL30\n"), data);
+        assert_ne!(key.encrypt("L68\nL31\n")[..NONCE_LEN], data[..NONCE_LEN]);
+        assert!(Key::generate().decrypt(&data).is_err());
+        let mut corrupted = data.clone();
+        corrupted[NONCE_LEN] ^= 1;
+        assert!(key.decrypt(&corrupted).is_err());
+        assert!(key.decrypt(&data[..4]).is_err());
+    }
+
+    #[test]
+    fn hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()), Ok(key));
+        assert!(Key::from_hex("00ff").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+    }
+}
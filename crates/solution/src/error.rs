@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+/// Error returned by every parser, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text
+    pub line: usize,
+    /// 1-based column of the offending text, in chars
+    pub column: usize,
+    /// offending text, empty when the input ended too early
+    pub text: String,
+    /// what was expected instead
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, text: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// error at 1-based `column` of `line`, the text being everything from there
+    pub fn at(line: &str, column: usize, expected: impl Into<String>) -> Self {
+        let text = line.chars().skip(column.saturating_sub(1)).collect::<String>();
+        Self::new(column, text, expected)
+    }
+
+    /// error found while parsing a single line, located at `line` of the whole input
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// error found while parsing a slice that starts `columns` chars into its line
+    pub fn shifted(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    /// error found while parsing `part`, located within `s` instead, `part` being a subslice of `s`
+    pub fn within(mut self, s: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize).saturating_sub(s.as_ptr() as usize);
+        let before = &s[..offset.min(s.len())];
+        let line = before.rfind('\n').map_or(before, |x| &before[x + 1..]);
+        if self.line == 1 {
+            self.column += line.chars().count();
+        }
+        self.line += line_number(s, part) - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "`{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column of `part` within `line`, `part` being a subslice of `line`.
+pub fn column(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line[..offset.min(line.len())].chars().count() + 1
+}
+
+/// 1-based line of `part` within `s`, `part` being a subslice of `s`.
+fn line_number(s: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(s.as_ptr() as usize);
+    s[..offset.min(s.len())].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = ParseError::new(2, "x", "a digit").on_line(3);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: expected a digit, found `x`"
+        );
+        let error = ParseError::new(1, "", "an operator").shifted(4);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected an operator, found nothing"
+        );
+    }
+
+    #[test]
+    fn columns() {
+        let line = "12  é4 5";
+        let parts = line.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(column(line, parts[0]), 1);
+        assert_eq!(column(line, parts[1]), 5);
+        assert_eq!(column(line, parts[2]), 8);
+        assert_eq!(ParseError::at(line, 5, "a number").text, "é4 5");
+        assert_eq!(ParseError::at(line, 0, "a number").text, line);
+    }
+
+    #[test]
+    fn within() {
+        let input = "1 2\n3 é4 5\n6";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new(2, "4", "a digit");
+        assert_eq!(
+            error.clone().within(line, &line[2..]),
+            error.clone().shifted(2)
+        );
+        assert_eq!(
+            error.clone().within(input, &line[2..]),
+            error.shifted(2).on_line(2)
+        );
+        assert_eq!(line_number(input, &line[2..]), 2);
+        let error = ParseError::new(1, "6", "a digit").on_line(2);
+        assert_eq!(error.clone().within(input, line), error.on_line(3));
+    }
+}
@@ -0,0 +1,303 @@
+//! Where puzzle inputs are read from.
+//!
+//! For a given day, the first of these that is set wins:
+//!
+//! 1. an explicit path, given on the command line, or `-` for stdin
+//! 2. the `AOC_INPUT` environment variable, same format as above
+//! 3. a directory laid out like `days/`, i.e. `DIR/DD/input.txt`,
+//!    given on the command line or through the `AOC_INPUTS` environment variable
+//! 4. `days/DD/input.txt` from the current directory,
+//!    then from the workspace root
+//!
+//! Directories may hold `DD/input.txt.enc` instead of `DD/input.txt`,
+//! decrypted on the fly with the local key, see [`crate::crypt`].
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::crypt::{EXTENSION, KEY_VAR, Key, key_file};
+
+pub const INPUT_VAR: &str = "AOC_INPUT";
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+pub(crate) const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Encrypted(PathBuf),
+}
+
+impl From<&str> for Source {
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Stdin(std::io::Error),
+    /// encrypted, without any key to decrypt it
+    Locked(PathBuf),
+    Decrypt {
+        path: PathBuf,
+        error: String,
+    },
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { day, tried } => {
+                let tried = tried
+                    .iter()
+                    .map(|x| x.display().to_string())
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "no input found for day {day:02}, tried {}",
+                    tried.join(", ")
+                )
+            }
+            Self::Read { path, error } => {
+                write!(f, "failed to read input {}: {error}", path.display())
+            }
+            Self::Stdin(error) => write!(f, "failed to read input from stdin: {error}"),
+            Self::Locked(path) => write!(
+                f,
+                "input {} is encrypted, set {KEY_VAR} or write the key in {} to decrypt it",
+                path.display(),
+                key_file().display()
+            ),
+            Self::Decrypt { path, error } => {
+                write!(f, "failed to decrypt input {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[derive(Debug, Default)]
+pub struct Inputs {
+    file: Option<Source>,
+    dir: Option<PathBuf>,
+    key: Option<Key>,
+    stdin: OnceLock<String>,
+}
+
+impl Inputs {
+    pub fn from_env() -> Self {
+        Self {
+            file: std::env::var(INPUT_VAR)
+                .ok()
+                .map(|x| Source::from(x.as_str())),
+            dir: std::env::var_os(INPUTS_VAR).map(PathBuf::from),
+            ..Default::default()
+        }
+    }
+
+    /// explicit file, takes precedence over anything else
+    pub fn file(mut self, file: Option<&str>) -> Self {
+        if let Some(file) = file {
+            self.file = Some(Source::from(file));
+        }
+        self
+    }
+
+    /// directory laid out like `days/`
+    pub fn dir(mut self, dir: Option<&Path>) -> Self {
+        if let Some(dir) = dir {
+            self.dir = Some(dir.to_path_buf());
+        }
+        self
+    }
+
+    /// key to decrypt inputs with, instead of the local one
+    pub fn key(mut self, key: Key) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn candidates(&self, day: u8) -> Vec<Source> {
+        if let Some(file) = &self.file {
+            return vec![file.clone()];
+        }
+        let dirs = match &self.dir {
+            Some(dir) => vec![dir.clone()],
+            None => vec![PathBuf::from("days"), workspace().join("days")],
+        };
+        let relative = PathBuf::from(format!("{day:02}/input.txt"));
+        dirs.into_iter()
+            .flat_map(|dir| {
+                let path = dir.join(&relative);
+                [
+                    Source::File(path.clone()),
+                    Source::Encrypted(encrypted(&path)),
+                ]
+            })
+            .collect()
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let candidates = self.candidates(day);
+        let mut tried = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let (path, found) = match candidate {
+                Source::Stdin => return self.read_stdin(),
+                Source::File(path) => {
+                    let found = std::fs::read_to_string(&path);
+                    (path, found.map(Ok))
+                }
+                Source::Encrypted(path) => {
+                    let found = std::fs::read(&path);
+                    let input = found.map(|x| self.decrypt(&path, &x));
+                    (path, input)
+                }
+            };
+            match found {
+                Ok(input) => return input,
+                Err(e) if self.file.is_some() || e.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(InputError::Read { path, error: e });
+                }
+                Err(_) => tried.push(path),
+            }
+        }
+        Err(InputError::Missing { day, tried })
+    }
+
+    fn decrypt(&self, path: &Path, data: &[u8]) -> Result<String, InputError> {
+        let key = match &self.key {
+            Some(key) => Some(key.clone()),
+            None => Key::load().map_err(|error| InputError::Decrypt {
+                path: path.to_path_buf(),
+                error,
+            })?,
+        };
+        let key = key.ok_or(InputError::Locked(path.to_path_buf()))?;
+        key.decrypt(data).map_err(|error| InputError::Decrypt {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+
+    fn read_stdin(&self) -> Result<String, InputError> {
+        if let Some(input) = self.stdin.get() {
+            return Ok(input.clone());
+        }
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+        Ok(self.stdin.get_or_init(|| input).clone())
+    }
+}
+
+/// root of the workspace, without the `..` leading to it when it exists
+fn workspace() -> PathBuf {
+    let root = Path::new(WORKSPACE);
+    root.canonicalize().unwrap_or(root.to_path_buf())
+}
+
+/// `path` of an input once encrypted, i.e. `input.txt.enc`
+pub fn encrypted(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(format!(".{EXTENSION}"));
+    PathBuf::from(encrypted)
+}
+
+/// Input of `day` from the workspace, for the tests depending on it,
+/// which are skipped when it is missing or cannot be decrypted without the key.
+pub fn committed(day: u8) -> Option<String> {
+    match Inputs::default().read(day) {
+        Ok(input) => Some(input),
+        Err(e @ (InputError::Missing { .. } | InputError::Locked(_))) => {
+            eprintln!("skipped: {e}");
+            None
+        }
+        Err(e) => panic!("{e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_order() {
+        let inputs = Inputs::default();
+        assert_eq!(
+            inputs.candidates(4)[..2],
+            [
+                Source::File(PathBuf::from("days/04/input.txt")),
+                Source::Encrypted(PathBuf::from("days/04/input.txt.enc")),
+            ]
+        );
+        let inputs = inputs.dir(Some(Path::new("accounts/alice")));
+        assert_eq!(
+            inputs.candidates(4),
+            vec![
+                Source::File(PathBuf::from("accounts/alice/04/input.txt")),
+                Source::Encrypted(PathBuf::from("accounts/alice/04/input.txt.enc")),
+            ]
+        );
+        let inputs = inputs.file(Some("-"));
+        assert_eq!(inputs.candidates(4), vec![Source::Stdin]);
+    }
+
+    #[test]
+    fn workspace_fallback() {
+        if let Some(input) = committed(1) {
+            assert!(!input.is_empty());
+        }
+    }
+
+    #[test]
+    fn encrypted() {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-inputs-{}", std::process::id()));
+        let path = super::encrypted(&dir.join("03/input.txt"));
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let key = Key::generate();
+        std::fs::write(&path, key.encrypt("987654321111111\n")).unwrap();
+        let inputs = Inputs::default().dir(Some(&dir));
+        let decrypted = inputs.key(key).read(3);
+        assert_eq!(decrypted.unwrap(), "987654321111111\n");
+        let inputs = Inputs::default().dir(Some(&dir)).key(Key::generate());
+        let error = inputs.read(3).unwrap_err();
+        assert!(matches!(error, InputError::Decrypt { .. }));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing() {
+        let inputs = Inputs::default().file(Some("nowhere/12.txt"));
+        let error = inputs.read(12).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to read input nowhere/12.txt: No such file or directory (os error 2)"
+        );
+        let inputs = Inputs::default().dir(Some(Path::new("nowhere")));
+        let error = inputs.read(12).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no input found for day 12, tried nowhere/12/input.txt, nowhere/12/input.txt.enc"
+        );
+        let inputs = Inputs::default();
+        let error = inputs.read(25).unwrap_err();
+        assert!(matches!(error, InputError::Missing { day: 25, ref tried } if tried.len() == 4));
+    }
+}
@@ -0,0 +1,188 @@
+use std::{any::Any, fmt::Display, process::ExitCode};
+
+use crate::input::Inputs;
+
+pub use error::{ParseError, column};
+
+pub mod crypt;
+mod error;
+pub mod input;
+pub mod parse;
+
+/// Common entry point implemented by every part of the `aoc-2025-DD` crates.
+///
+/// Each day exposes `Part1` and `Part2` unit structs so that tooling can
+/// parse and solve any day without knowing its bespoke API.
+pub trait Solution {
+    /// day of the puzzle, from 1 to 12
+    const DAY: u8;
+    /// part of the puzzle, either 1 or 2
+    const PART: u8;
+    /// what the answer stands for, e.g. "password is"
+    const LABEL: &'static str;
+
+    type Input: 'static;
+    type Answer: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn solve(input: Self::Input) -> Self::Answer;
+
+    fn run(input: &str) -> Result<Self::Answer, ParseError> {
+        Self::parse(input).map(Self::solve)
+    }
+}
+
+/// Value a part solves for, which some inputs have none of.
+pub trait Answer {
+    /// the answer as submitted, or why there is none
+    fn submission(&self) -> Result<String, String>;
+}
+
+macro_rules! answers {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn submission(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answers!(u32, u64, u128, usize, i64, String);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn submission(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.submission(),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Shared `main` of every day binary, solving each of its parts,
+/// which takes an optional input path as its first argument (see [`input`]).
+pub fn main(parts: &[Entry]) -> ExitCode {
+    let Some(day) = parts.first().map(|x| x.day) else {
+        return ExitCode::SUCCESS;
+    };
+    let file = std::env::args().nth(1);
+    let inputs = Inputs::from_env().file(file.as_deref());
+    let input = match inputs.read(day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for part in parts {
+        match part.run(&input) {
+            Ok(answer) => println!("{}: {answer}", part.label),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Type-erased [`Solution`], so that every day can be listed side by side.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub label: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    /// panics if not given the output of `parse`
+    pub solve: fn(Box<dyn Any>) -> Result<String, String>,
+}
+
+impl Entry {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            part: S::PART,
+            label: S::LABEL,
+            parse: |input| S::parse(input).map(|x| Box::new(x) as Box<dyn Any>),
+            solve: |input| match input.downcast::<S::Input>() {
+                Ok(input) => S::solve(*input).submission(),
+                Err(_) => panic!(
+                    "day {:02} part {:02} given a foreign input",
+                    S::DAY,
+                    S::PART
+                ),
+            },
+        }
+    }
+
+    /// the answer, or why there is none, parse errors included
+    pub fn run(&self, input: &str) -> Result<String, String> {
+        (self.parse)(input)
+            .map_err(|e| e.to_string())
+            .and_then(self.solve)
+    }
+}
+
+impl std::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {:02} part {:02}", self.day, self.part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const PART: u8 = 1;
+        const LABEL: &'static str = "sum";
+
+        type Input = Vec<usize>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, x)| {
+                    x.parse()
+                        .map_err(|_| ParseError::new(1, x, "a number").on_line(i + 1))
+                })
+                .collect()
+        }
+
+        fn solve(input: Self::Input) -> Self::Answer {
+            input.iter().sum()
+        }
+    }
+
+    #[test]
+    fn run() {
+        assert_eq!(Sum::run("1\n2\n3"), Ok(6));
+        assert_eq!(
+            Sum::run("1\nx"),
+            Err(ParseError::new(1, "x", "a number").on_line(2))
+        );
+    }
+
+    #[test]
+    fn entry() {
+        let entry = Entry::of::<Sum>();
+        assert_eq!((entry.day, entry.part, entry.label), (0, 1, "sum"));
+        assert_eq!(entry.run("4\n5").unwrap(), "9");
+        assert_eq!(
+            entry.run("x").unwrap_err().to_string(),
+            "line 1, column 1: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn no_answer() {
+        assert_eq!(Ok::<u32, String>(3).submission(), Ok("3".to_string()));
+        let none = Err::<u32, _>("no way to get there");
+        assert_eq!(none.submission(), Err("no way to get there".to_string()));
+    }
+}
@@ -0,0 +1,176 @@
+//! Parsers for the shapes puzzle inputs come in.
+//!
+//! Each one locates its errors within the text it was given, so that they point
+//! at the exact line and column of the whole input once every caller did the same,
+//! see [`ParseError::within`].
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// `s` as a number, `expected` telling what it stands for, e.g. "a width"
+pub fn number<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(1, s, expected))
+}
+
+/// parts of `s` between each `separator`, parsed by `f`
+pub fn list<'a, T>(
+    s: &'a str,
+    separator: char,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.split(separator)
+        .map(|part| f(part).map_err(|e| e.within(s, part)))
+        .collect()
+}
+
+/// words of `s`, separated by any whitespace, parsed by `f`
+pub fn words<'a, T>(
+    s: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace()
+        .map(|word| f(word).map_err(|e| e.within(s, word)))
+        .collect()
+}
+
+/// lines of `s` parsed by `f`, blank ones skipped
+pub fn lines<'a, T>(
+    s: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| f(line).map_err(|e| e.within(s, line)))
+        .collect()
+}
+
+/// lines of `s` parsed by `f` along their 1-based line number, blank ones skipped
+pub fn numbered_lines<'a, T>(
+    s: &'a str,
+    mut f: impl FnMut(usize, &'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| f(i + 1, line).map_err(|e| e.within(s, line)))
+        .collect()
+}
+
+/// `left` and `right` numbers of `s` around `separator`, e.g. `x,y`,
+/// with `expected` telling what the pair stands for and `item` each number
+pub fn pair<T: FromStr>(
+    s: &str,
+    separator: char,
+    expected: &str,
+    item: &str,
+) -> Result<(T, T), ParseError> {
+    let Some((left, right)) = s.split_once(separator) else {
+        return Err(ParseError::new(1, s, expected));
+    };
+    let left = number(left, item).map_err(|e| e.within(s, left))?;
+    let right = number(right, item).map_err(|e| e.within(s, right))?;
+    Ok((left, right))
+}
+
+/// `start-end` numbers of `s`, as they come
+pub fn range<T: FromStr>(s: &str) -> Result<(T, T), ParseError> {
+    pair(s, '-', "a range (start-end)", "a number")
+}
+
+/// each char of the line `s` mapped by `f`, `expected` telling which ones it accepts
+pub fn chars<T>(
+    s: &str,
+    expected: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| f(c).ok_or_else(|| ParseError::new(i + 1, c, expected)))
+        .collect()
+}
+
+/// sections of `s` separated by blank lines, as slices of `s`
+pub fn sections(s: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut section: Option<(usize, usize)> = None;
+    for line in s.lines() {
+        let start = line.as_ptr() as usize - s.as_ptr() as usize;
+        if !line.trim().is_empty() {
+            let end = start + line.len();
+            section = Some(section.map_or((start, end), |(start, _)| (start, end)));
+        } else if let Some((start, end)) = section.take() {
+            sections.push(&s[start..end]);
+        }
+    }
+    sections.extend(section.map(|(start, end)| &s[start..end]));
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsers() {
+        assert_eq!(number::<u8>("12", "a size"), Ok(12));
+        assert_eq!(list("1,2,3", ',', |x| number(x, "a")), Ok(vec![1, 2, 3]));
+        assert_eq!(words(" 1  2 ", |x| number(x, "a")), Ok(vec![1, 2]));
+        assert_eq!(lines("1\n\n2\n", |x| number(x, "a")), Ok(vec![1, 2]));
+        assert_eq!(
+            numbered_lines("1\n\n2\n", |i, x| Ok((i, number(x, "a")?))),
+            Ok(vec![(1, 1), (3, 2)])
+        );
+        assert_eq!(
+            pair("3,4", ',', "a point (x,y)", "a coordinate"),
+            Ok((3, 4))
+        );
+        assert_eq!(range("3-5"), Ok((3, 5)));
+        assert_eq!(
+            chars(".#", "`.` or `#`", |c| Some(c == '#')),
+            Ok(vec![false, true])
+        );
+        assert_eq!(
+            sections("1\n2\n\n\n3\n\n"),
+            vec!["1\n2", "3"],
+            "sections are slices of the input"
+        );
+    }
+
+    fn digit(s: &str) -> Result<u8, ParseError> {
+        number(s, "a digit")
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str, expected: &str| ParseError::new(1, text, expected);
+        assert_eq!(number::<u8>("256", "a size"), Err(error("256", "a size")));
+        assert_eq!(
+            lines("1,2\n\n3,x", |line| list(line, ',', digit)),
+            Err(error("x", "a digit").shifted(2).on_line(3))
+        );
+        assert_eq!(
+            lines("1\n 2 x", |line| words(line, digit)),
+            Err(error("x", "a digit").shifted(3).on_line(2))
+        );
+        assert_eq!(
+            pair::<u8>("3;4", ',', "a point (x,y)", "a coordinate"),
+            Err(error("3;4", "a point (x,y)"))
+        );
+        assert_eq!(
+            pair::<u8>("3,-4", ',', "a point (x,y)", "a coordinate"),
+            Err(error("-4", "a coordinate").shifted(2))
+        );
+        assert_eq!(range::<u8>("3-"), Err(error("", "a number").shifted(2)));
+        assert_eq!(
+            chars("..x", "`.` or `#`", |c| (c != 'x').then_some(c)),
+            Err(error("x", "`.` or `#`").shifted(2))
+        );
+        let input = "1\n\n2\n3x";
+        let section = sections(input)[1];
+        assert_eq!(
+            lines(section, digit).map_err(|e| e.within(input, section)),
+            Err(error("3x", "a digit").on_line(4))
+        );
+    }
+}
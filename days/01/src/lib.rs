@@ -0,0 +1,766 @@
+use std::{
+    ops::{AddAssign, RangeInclusive, SubAssign},
+    str::FromStr,
+};
+
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{number, numbered_lines},
+};
+
+/// Dial to build, the puzzle one having 100 positions, starting at 50 with a target of 0.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Config {
+    pub positions: usize,
+    pub start: usize,
+    pub target: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            positions: 100,
+            start: 50,
+            target: 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dial {
+    positions: usize,
+    points: usize,
+    target: usize,
+    /// rotations ending on the target
+    landed: usize,
+    /// clicks on the target before the end of their rotation, which a few
+    /// rotations of `usize::MAX` clicks on a small dial would overflow
+    passed: u128,
+}
+
+impl std::cmp::PartialEq<usize> for Dial {
+    fn eq(&self, other: &usize) -> bool {
+        self.points == *other
+    }
+}
+
+impl std::ops::SubAssign<usize> for Dial {
+    fn sub_assign(&mut self, rhs: usize) {
+        let first = self.first_hit(Rotation::Left(rhs));
+        let rhs_mod = rhs % self.positions;
+        self.points = match self.points.checked_sub(rhs_mod) {
+            Some(points) => points,
+            None => self.positions - (rhs_mod - self.points),
+        };
+        self.count(rhs, first);
+    }
+}
+
+impl std::ops::AddAssign<usize> for Dial {
+    fn add_assign(&mut self, rhs: usize) {
+        let first = self.first_hit(Rotation::Right(rhs));
+        let rhs_mod = rhs % self.positions;
+        let left = self.positions - self.points;
+        self.points = match rhs_mod.checked_sub(left) {
+            Some(points) => points,
+            None => self.points + rhs_mod,
+        };
+        self.count(rhs, first);
+    }
+}
+
+/// clicks to the right from `from` to `to`, on a dial of `positions`
+fn distance(from: usize, to: usize, positions: usize) -> usize {
+    match to.checked_sub(from) {
+        Some(clicks) => clicks,
+        None => positions - (from - to),
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(Config::default())
+    }
+}
+
+impl Dial {
+    pub fn new(config: Config) -> Self {
+        assert!(config.positions > 0, "a dial has positions");
+        assert!(config.start < config.positions, "start on the dial");
+        assert!(config.target < config.positions, "target on the dial");
+        Dial {
+            positions: config.positions,
+            points: config.start,
+            target: config.target,
+            landed: 0,
+            passed: 0,
+        }
+    }
+
+    /// click of `rotation` first reaching the target, if it turned long enough
+    fn first_hit(&self, rotation: Rotation) -> usize {
+        let first = match rotation {
+            Rotation::Left(_) => distance(self.target, self.points, self.positions),
+            Rotation::Right(_) => distance(self.points, self.target, self.positions),
+        };
+        if first == 0 { self.positions } else { first }
+    }
+
+    /// counts the clicks on the target among `clicks`, the first one being `first`
+    fn count(&mut self, clicks: usize, first: usize) {
+        let hits = match clicks.checked_sub(first) {
+            Some(left) => left / self.positions + 1,
+            None => 0,
+        };
+        self.tally(hits);
+    }
+
+    /// clicks of `rotation` on the target, from 1, or 0 for a rotation of no click on it
+    pub fn hits(&self, rotation: Rotation) -> impl Iterator<Item = usize> + use<> {
+        let clicks = rotation.clicks();
+        let idle = clicks == 0 && self.is_target();
+        (self.first_hit(rotation)..=clicks)
+            .step_by(self.positions)
+            .chain(idle.then_some(0))
+    }
+
+    /// adds the `hits` of the target by the rotation just done
+    fn tally(&mut self, hits: usize) {
+        let landed = self.is_target();
+        self.landed += usize::from(landed);
+        // a rotation of no click lands where it started
+        self.passed += hits.saturating_sub(usize::from(landed)) as u128;
+    }
+
+    pub fn position(&self) -> usize {
+        self.points
+    }
+    pub fn positions(&self) -> usize {
+        self.positions
+    }
+    pub fn is_target(&self) -> bool {
+        self.points == self.target
+    }
+    pub fn rotate(&mut self, rotation: Rotation) {
+        match rotation {
+            Rotation::Left(clicks) => self.sub_assign(clicks),
+            Rotation::Right(clicks) => self.add_assign(clicks),
+        }
+    }
+    /// rotations which ended on the target
+    pub fn landed(&self) -> usize {
+        self.landed
+    }
+    /// clicks on the target in the middle of a rotation
+    pub fn passed(&self) -> u128 {
+        self.passed
+    }
+    /// every click on the target
+    pub fn password(&self) -> u128 {
+        self.landed as u128 + self.passed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    Left(usize),
+    Right(usize),
+}
+
+impl Rotation {
+    pub fn clicks(&self) -> usize {
+        match self {
+            Rotation::Left(clicks) | Rotation::Right(clicks) => *clicks,
+        }
+    }
+
+    /// positions pointed at from `from` besides the whole turns, as the lowest one
+    /// going right on a dial of `positions`, and how many follow it
+    pub fn partial_turn(&self, from: usize, positions: usize) -> (usize, usize) {
+        let clicks = self.clicks() % positions;
+        let first = match self {
+            Rotation::Left(_) => distance(clicks, from, positions),
+            Rotation::Right(_) => distance(positions - 1, from, positions),
+        };
+        (first, clicks)
+    }
+}
+
+impl std::fmt::Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Rotation::Left(clicks) => format!("L{}", clicks),
+                Rotation::Right(clicks) => format!("R{}", clicks),
+            }
+        )
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let direction = chars.next();
+        let clicks = chars.as_str();
+        let clicks = number(clicks, "a number of clicks").map_err(|e| e.within(s, clicks));
+        match direction {
+            Some('L') => Ok(Rotation::Left(clicks?)),
+            Some('R') => Ok(Rotation::Right(clicks?)),
+            _ => Err(ParseError::at(s, 1, "a rotation (L or R)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitKind {
+    /// the rotation ended on the target
+    Landed,
+    /// the rotation went on past the target
+    PassedThrough,
+}
+
+/// Click of a rotation on the target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// line of the rotation in the input
+    pub line: usize,
+    /// click of the rotation on the target, from 1
+    pub click: usize,
+    pub kind: HitKind,
+    /// dial position before the rotation
+    pub before: usize,
+    /// dial position after the rotation
+    pub after: usize,
+}
+
+impl std::fmt::Display for Hit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            HitKind::Landed => "landed",
+            HitKind::PassedThrough => "passed through",
+        };
+        write!(
+            f,
+            "line {}, click {}: {kind}, {} -> {}",
+            self.line, self.click, self.before, self.after
+        )
+    }
+}
+
+/// Statistics over the clicks of a whole sequence of rotations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Visits {
+    /// clicks pointing at each position
+    pub clicks: Vec<u128>,
+    /// position after the last rotation
+    pub last: usize,
+    /// clicks turned either way
+    pub distance: u128,
+}
+
+impl Visits {
+    /// position most clicks pointed at, the lowest one among ties
+    pub fn most_visited(&self) -> usize {
+        let most = self.clicks.iter().max().unwrap_or(&0);
+        self.clicks.iter().position(|x| x == most).unwrap_or(0)
+    }
+}
+
+/// Password to look for, counted as in either part of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Password {
+    /// rotations ending on the target
+    Landed(usize),
+    /// clicks pointing at the target
+    Clicked(u128),
+}
+
+impl Password {
+    fn matches(&self, dial: &Dial) -> bool {
+        match self {
+            Password::Landed(x) => dial.landed() == *x,
+            Password::Clicked(x) => dial.password() == *x,
+        }
+    }
+}
+
+/// Rotations along their line in the input.
+pub struct Rotations(Vec<(usize, Rotation)>);
+
+impl Rotations {
+    /// dial built from `config` after every rotation
+    pub fn turn(&self, config: Config) -> Dial {
+        let mut dial = Dial::new(config);
+        for (_, rotation) in self.0.iter() {
+            dial.rotate(*rotation);
+        }
+        dial
+    }
+
+    /// every click on the target of the dial built from `config`, as many as its password
+    pub fn trace(&self, config: Config) -> impl Iterator<Item = Hit> + '_ {
+        let mut dial = Dial::new(config);
+        self.0.iter().flat_map(move |(line, rotation)| {
+            let before = dial.position();
+            let hits = dial.hits(*rotation);
+            dial.rotate(*rotation);
+            let (line, after, clicks) = (*line, dial.position(), rotation.clicks());
+            hits.map(move |click| Hit {
+                line,
+                click,
+                kind: match click == clicks {
+                    true => HitKind::Landed,
+                    false => HitKind::PassedThrough,
+                },
+                before,
+                after,
+            })
+        })
+    }
+
+    /// clicks pointing at each position of the dial built from `config`, counted with
+    /// a difference array over the partial turns, whole turns pointing once at every position
+    pub fn visits(&self, config: Config) -> Visits {
+        let mut dial = Dial::new(config);
+        let positions = dial.positions();
+        let mut turns = 0;
+        let mut distance = 0;
+        let mut diff = vec![0i128; positions + 1];
+        for (_, rotation) in self.0.iter() {
+            let clicks = rotation.clicks();
+            distance += clicks as u128;
+            turns += (clicks / positions) as u128;
+            let (first, count) = rotation.partial_turn(dial.position(), positions);
+            let end = first + count;
+            diff[first] += 1;
+            if end <= positions {
+                diff[end] -= 1;
+            } else {
+                diff[positions] -= 1;
+                diff[0] += 1;
+                diff[end - positions] -= 1;
+            }
+            dial.rotate(*rotation);
+        }
+        let mut partial = 0;
+        let clicks = diff[..positions]
+            .iter()
+            .map(|x| {
+                partial += x;
+                turns + partial as u128
+            })
+            .collect();
+        Visits {
+            clicks,
+            last: dial.position(),
+            distance,
+        }
+    }
+
+    /// starts of the dial built from `config` ending with `password`, whatever its own start,
+    /// none when its target is not on the dial
+    pub fn starts(&self, config: Config, password: Password) -> Vec<usize> {
+        if config.target >= config.positions {
+            return vec![];
+        }
+        (0..config.positions)
+            .filter(|&start| password.matches(&self.turn(Config { start, ..config })))
+            .collect()
+    }
+
+    /// sizes among `sizes` of the dial built from `config` ending with `password`,
+    /// whatever its own size, skipping those too small to hold its start and target
+    pub fn sizes(
+        &self,
+        config: Config,
+        sizes: RangeInclusive<usize>,
+        password: Password,
+    ) -> Vec<usize> {
+        let smallest = config.start.max(config.target) + 1;
+        sizes
+            .filter(|&positions| positions >= smallest)
+            .filter(|&positions| {
+                password.matches(&self.turn(Config {
+                    positions,
+                    ..config
+                }))
+            })
+            .collect()
+    }
+
+    /// rotations ending on zero
+    pub fn part1(&self) -> usize {
+        self.turn(Config::default()).landed()
+    }
+
+    /// clicks landing on zero
+    pub fn part2(&self) -> u128 {
+        self.turn(Config::default()).password()
+    }
+}
+
+impl FromStr for Rotations {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rotations = numbered_lines(s, |i, line| Ok((i, Rotation::from_str(line)?)))?;
+        Ok(Self(rotations))
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "password is";
+
+    type Input = Rotations;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Rotations::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "password is";
+
+    type Input = Rotations;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Rotations::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    fn at(start: usize) -> Dial {
+        Dial::new(Config {
+            start,
+            ..Config::default()
+        })
+    }
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn wrapping_sub() {
+        let mut zero = at(0);
+        zero -= 1;
+        assert_eq!(zero, 99);
+        let mut zero = at(0);
+        zero -= 100;
+        assert_eq!(zero, 0);
+        let mut zero = at(0);
+        zero -= 101;
+        assert_eq!(zero, 99);
+    }
+
+    #[test]
+    fn wrapping_add() {
+        let mut zero = at(0);
+        zero += 1;
+        assert_eq!(zero, 1);
+        let mut zero = at(0);
+        zero += 100;
+        assert_eq!(zero, 0);
+        let mut zero = at(0);
+        zero += 101;
+        assert_eq!(zero, 1);
+    }
+
+    #[test]
+    fn rotations() {
+        let mut zero = at(0);
+        zero.rotate(Rotation::Left(1));
+        assert_eq!(zero, 99);
+        let mut zero = at(0);
+        zero.rotate(Rotation::Left(100));
+        assert_eq!(zero, 0);
+        let mut zero = at(0);
+        zero.rotate(Rotation::Left(101));
+        assert_eq!(zero, 99);
+        let mut zero = at(0);
+        zero.rotate(Rotation::Right(1));
+        assert_eq!(zero, 1);
+        let mut zero = at(0);
+        zero.rotate(Rotation::Right(100));
+        assert_eq!(zero, 0);
+        let mut zero = at(0);
+        zero.rotate(Rotation::Right(101));
+        assert_eq!(zero, 1);
+    }
+
+    #[test]
+    fn deserialization() {
+        let left = "L10";
+        let right = "R10";
+        assert_eq!(left.parse(), Ok(Rotation::Left(10)));
+        assert_eq!(right.parse(), Ok(Rotation::Right(10)));
+        assert_eq!(
+            "U10".parse::<Rotation>(),
+            Err(ParseError::new(1, "U10", "a rotation (L or R)"))
+        );
+        assert_eq!(
+            "L1O".parse::<Rotation>(),
+            Err(ParseError::new(2, "1O", "a number of clicks"))
+        );
+        assert_eq!(
+            Part1::run("L1\nL1O").err(),
+            Some(ParseError::new(2, "1O", "a number of clicks").on_line(2))
+        );
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(Part1::run(EXAMPLE), Ok(3));
+        assert_eq!(Part2::run(EXAMPLE), Ok(6));
+    }
+
+    #[test]
+    fn inverse() {
+        let rotations = Rotations::from_str(EXAMPLE).unwrap();
+        let config = Config::default();
+        assert_eq!(rotations.starts(config, Password::Landed(3)), vec![50]);
+        let starts = rotations.starts(config, Password::Clicked(6));
+        assert_eq!(starts, (37..=54).chain([96, 97]).collect::<Vec<_>>());
+        assert_eq!(
+            rotations.sizes(config, 1..=200, Password::Landed(3)),
+            vec![55, 56, 84, 86, 100, 168]
+        );
+        let sizes = rotations.sizes(config, 0..=200, Password::Clicked(6));
+        assert_eq!(sizes, (57..=84).chain([86, 100]).collect::<Vec<_>>());
+        let off = Config {
+            positions: 10,
+            start: 0,
+            target: 10,
+        };
+        assert_eq!(rotations.starts(off, Password::Landed(0)), vec![]);
+        let empty = Config {
+            positions: 0,
+            ..off
+        };
+        assert_eq!(rotations.starts(empty, Password::Landed(0)), vec![]);
+    }
+
+    #[test]
+    fn config() {
+        let mut dial = Dial::new(Config {
+            positions: 10,
+            start: 5,
+            target: 3,
+        });
+        dial.rotate(Rotation::Right(8));
+        assert_eq!((dial.position(), dial.landed(), dial.passed()), (3, 1, 0));
+        dial.rotate(Rotation::Left(15));
+        assert_eq!((dial.position(), dial.landed(), dial.passed()), (8, 1, 1));
+        dial.rotate(Rotation::Left(25));
+        assert_eq!((dial.position(), dial.landed(), dial.passed()), (3, 2, 3));
+        dial.rotate(Rotation::Right(0));
+        assert_eq!((dial.position(), dial.landed(), dial.passed()), (3, 3, 3));
+        let rotations = Rotations::from_str(EXAMPLE).unwrap();
+        let dial = rotations.turn(Config {
+            positions: 100,
+            start: 50,
+            target: 32,
+        });
+        assert_eq!((dial.position(), dial.landed(), dial.passed()), (32, 1, 4));
+    }
+
+    /// Dial going round one click at a time, the reference of the closed forms.
+    struct Reference {
+        config: Config,
+        position: usize,
+        landed: usize,
+        passed: u128,
+    }
+
+    impl Reference {
+        fn new(config: Config) -> Self {
+            Self {
+                config,
+                position: config.start,
+                landed: 0,
+                passed: 0,
+            }
+        }
+
+        /// whole turns of `rotation`, and the positions pointed at by each click of the rest
+        fn rotate(&mut self, rotation: Rotation) -> (usize, Vec<usize>) {
+            let positions = self.config.positions;
+            let (turns, rest) = (rotation.clicks() / positions, rotation.clicks() % positions);
+            // a whole turn points once at every position and ends where it started,
+            // so the rest goes first and the last click ends a turn if there is any
+            let clicks = (1..=rest)
+                .map(|click| {
+                    self.position = match rotation {
+                        Rotation::Left(_) => (self.position + positions - 1) % positions,
+                        Rotation::Right(_) => (self.position + 1) % positions,
+                    };
+                    if self.position == self.config.target && (click < rest || turns > 0) {
+                        self.passed += 1;
+                    }
+                    self.position
+                })
+                .collect();
+            self.passed += turns as u128;
+            if self.position == self.config.target {
+                self.landed += 1;
+                if turns > 0 {
+                    self.passed -= 1;
+                }
+            }
+            (turns, clicks)
+        }
+    }
+
+    fn any_rotation() -> impl Strategy<Value = Rotation> {
+        prop_oneof![
+            (0..1000usize).prop_map(Rotation::Left),
+            (0..1000usize).prop_map(Rotation::Right),
+            (usize::MAX / 2..=usize::MAX).prop_map(Rotation::Left),
+            (usize::MAX / 2..=usize::MAX).prop_map(Rotation::Right),
+        ]
+    }
+
+    fn any_config() -> impl Strategy<Value = Config> {
+        (1..200usize).prop_flat_map(|positions| {
+            (0..positions, 0..positions).prop_map(move |(start, target)| Config {
+                positions,
+                start,
+                target,
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn closed_form(config in any_config(), rotations in vec(any_rotation(), 0..50)) {
+            let mut dial = Dial::new(config);
+            let mut reference = Reference::new(config);
+            for rotation in rotations {
+                let (password, hits) = (dial.password(), dial.hits(rotation));
+                dial.rotate(rotation);
+                reference.rotate(rotation);
+                prop_assert_eq!(
+                    (dial.position(), dial.landed(), dial.passed()),
+                    (reference.position, reference.landed, reference.passed)
+                );
+                if rotation.clicks() < 1000 {
+                    prop_assert_eq!(hits.count() as u128, dial.password() - password);
+                }
+            }
+        }
+
+        #[test]
+        fn visits_by_clicks(config in any_config(), rotations in vec(any_rotation(), 0..50)) {
+            let mut visited = vec![0; config.positions];
+            let mut reference = Reference::new(config);
+            for rotation in rotations.iter() {
+                let (turns, clicks) = reference.rotate(*rotation);
+                visited.iter_mut().for_each(|x| *x += turns as u128);
+                for position in clicks {
+                    visited[position] += 1;
+                }
+            }
+            let rotations = rotations.into_iter().enumerate().map(|(i, x)| (i + 1, x));
+            let visits = Rotations(rotations.collect()).visits(config);
+            prop_assert_eq!(visits.clicks, visited);
+            prop_assert_eq!(visits.last, reference.position);
+        }
+    }
+
+    #[test]
+    fn trace() {
+        let rotations = Rotations::from_str(EXAMPLE).unwrap();
+        let hit = |line, click, kind, before, after| Hit {
+            line,
+            click,
+            kind,
+            before,
+            after,
+        };
+        let trace = rotations.trace(Config::default()).collect::<Vec<_>>();
+        assert_eq!(
+            trace,
+            [
+                hit(1, 50, HitKind::PassedThrough, 50, 82),
+                hit(3, 48, HitKind::Landed, 52, 0),
+                hit(5, 5, HitKind::PassedThrough, 95, 55),
+                hit(6, 55, HitKind::Landed, 55, 0),
+                hit(8, 99, HitKind::Landed, 99, 0),
+                hit(10, 14, HitKind::PassedThrough, 14, 32),
+            ]
+        );
+        assert_eq!(
+            trace[0].to_string(),
+            "line 1, click 50: passed through, 50 -> 82"
+        );
+        let rotations = Rotations::from_str("R0\n\nR250\nL0").unwrap();
+        let config = Config {
+            start: 0,
+            ..Config::default()
+        };
+        let trace = rotations.trace(config).collect::<Vec<_>>();
+        assert_eq!(
+            trace,
+            [
+                hit(1, 0, HitKind::Landed, 0, 0),
+                hit(3, 100, HitKind::PassedThrough, 0, 50),
+                hit(3, 200, HitKind::PassedThrough, 0, 50),
+            ]
+        );
+        assert_eq!(trace.len() as u128, rotations.turn(config).password());
+    }
+
+    #[test]
+    fn visits() {
+        let rotations = Rotations::from_str("R1000\nL3\nR7").unwrap();
+        let visits = rotations.visits(Config {
+            positions: 10,
+            start: 5,
+            target: 0,
+        });
+        let mut clicks = vec![100; 10];
+        for x in [4, 3, 2, 3, 4, 5, 6, 7, 8, 9] {
+            clicks[x] += 1;
+        }
+        assert_eq!(visits.clicks, clicks);
+        assert_eq!((visits.last, visits.distance), (9, 1010));
+        assert_eq!(visits.most_visited(), 3);
+        let visits = Rotations::from_str(EXAMPLE)
+            .unwrap()
+            .visits(Config::default());
+        assert_eq!(visits.clicks[0], 6);
+        assert_eq!(visits.clicks.iter().sum::<u128>(), visits.distance);
+    }
+
+    #[test]
+    fn large_rotation() {
+        let mut dial = Dial::default();
+        dial.rotate(Rotation::Right(1000));
+        assert_eq!(dial, 50);
+        assert_eq!(dial.password(), 10);
+    }
+}
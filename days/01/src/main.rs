@@ -0,0 +1,49 @@
+use std::{process::ExitCode, str::FromStr};
+
+use aoc_2025_01::{Config, Part1, Part2, Rotations};
+use aoc_2025_solution::{Entry, input::Inputs};
+
+fn main() -> ExitCode {
+    let file = std::env::args().nth(2);
+    match std::env::args().nth(1).as_deref() {
+        Some("--trace") => run(file.as_deref(), trace),
+        Some("--visits") => run(file.as_deref(), visits),
+        _ => aoc_2025_solution::main(&[Entry::of::<Part1>(), Entry::of::<Part2>()]),
+    }
+}
+
+/// reads the rotations from the optional input path, then hands them to `f`
+fn run(file: Option<&str>, f: fn(&Rotations)) -> ExitCode {
+    let rotations = match Inputs::from_env().file(file).read(1) {
+        Ok(input) => Rotations::from_str(&input).map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    match rotations {
+        Ok(rotations) => {
+            f(&rotations);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// prints every click on zero
+fn trace(rotations: &Rotations) {
+    for hit in rotations.trace(Config::default()) {
+        println!("{hit}");
+    }
+}
+
+/// prints how many clicks pointed at each position, then a summary
+fn visits(rotations: &Rotations) {
+    let visits = rotations.visits(Config::default());
+    for (position, clicks) in visits.clicks.iter().enumerate() {
+        println!("{position:>2}: {clicks}");
+    }
+    println!("final position: {}", visits.last);
+    println!("most visited: {}", visits.most_visited());
+    println!("distance: {}", visits.distance);
+}
@@ -0,0 +1,224 @@
+use std::{ops::Div, str::FromStr};
+
+use aoc_2025_interval::{Interval, parse_list};
+use aoc_2025_solution::{ParseError, Solution, parse::number};
+
+/// Product ID, along its digits.
+#[derive(Debug)]
+pub struct ID {
+    value: usize,
+    digits: String,
+}
+
+impl ID {
+    pub fn new(id: &str) -> Result<Self, ParseError> {
+        Ok(Self::new_with(number(id, "an id")?))
+    }
+    pub fn new_with(id: usize) -> Self {
+        Self {
+            value: id,
+            digits: id.to_string(),
+        }
+    }
+    /// made of a sequence of digits repeated twice
+    pub fn invalid(&self) -> bool {
+        if self.digits.len().rem_euclid(2) > 0 {
+            return false;
+        }
+        let (left, right) = self.digits.split_at(self.digits.len().div(2));
+        for (left, right) in left.chars().zip(right.chars()) {
+            if left != right {
+                return false;
+            }
+        }
+        true
+    }
+    /// made of a sequence of digits repeated at least twice
+    pub fn repeated(&self) -> bool {
+        let chars = self.digits.chars().collect::<Vec<_>>();
+        let len = chars.len();
+        let half = len / 2;
+        for i in 1.. {
+            if i > half {
+                break;
+            }
+            if len.rem_euclid(i) != 0 {
+                continue;
+            }
+            if same(i, chars.as_slice()) {
+                return true;
+            }
+        }
+        false
+    }
+    pub fn to_usize(&self) -> usize {
+        self.value
+    }
+}
+
+impl std::fmt::Display for ID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.digits)
+    }
+}
+
+/// whether `chars` are made of at least two same chunks of `divisor` chars,
+/// their length being a multiple of it
+fn same(divisor: usize, chars: &[char]) -> bool {
+    let mut iter = chars.chunks(divisor.max(1));
+    if iter.len() < 2 {
+        return false;
+    }
+    let Some(mut current) = iter.next() else {
+        return false;
+    };
+    for next in iter {
+        if current != next {
+            return false;
+        }
+        current = next;
+    }
+    true
+}
+
+pub struct IDRanges(Vec<Interval>);
+
+impl IDRanges {
+    pub fn sum_invalids(&self, invalid: fn(&ID) -> bool) -> usize {
+        let mut invalids = Vec::with_capacity(self.0.len());
+        for range in self.0.iter() {
+            for id in range.iter().map(ID::new_with) {
+                if invalid(&id) {
+                    invalids.push(id.to_usize());
+                }
+            }
+        }
+        invalids.iter().sum()
+    }
+
+    pub fn part1(&self) -> usize {
+        self.sum_invalids(ID::invalid)
+    }
+
+    pub fn part2(&self) -> usize {
+        self.sum_invalids(ID::repeated)
+    }
+}
+
+impl FromStr for IDRanges {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(parse_list(s.trim_end(), ',')?))
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "invalid(s)";
+
+    type Input = IDRanges;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        IDRanges::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "invalid(s)";
+
+    type Input = IDRanges;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        IDRanges::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_ids() {
+        let id = ID::new("12345").unwrap();
+        assert!(!id.invalid() && !id.repeated());
+        let id = ID::new("123122").unwrap();
+        assert!(!id.invalid() && !id.repeated());
+    }
+
+    #[test]
+    fn invalid_ids() {
+        let id = ID::new("11").unwrap();
+        assert!(id.invalid() && id.repeated());
+        let id = ID::new("1212").unwrap();
+        assert!(id.invalid() && id.repeated());
+        let id = ID::new("121212").unwrap();
+        assert!(!id.invalid() && id.repeated());
+    }
+
+    use test_case::test_case;
+
+    #[test_case("11-22", 2, 2 ; "range-11-22")]
+    #[test_case("95-115", 1, 2 ; "range-95-115")]
+    #[test_case("998-1012", 1, 2 ; "range-998-1012")]
+    #[test_case("1188511880-1188511890", 1, 1 ; "range-1188511880-1188511890")]
+    #[test_case("222220-222224", 1, 1 ; "range-222220-222224")]
+    #[test_case("1698522-1698528", 0, 0 ; "range-1698522-1698528")]
+    #[test_case("446443-446449", 1, 1 ; "range-446443-446449")]
+    #[test_case("38593856-38593862", 1, 1 ; "range-38593856-38593862")]
+    #[test_case("565653-565659", 0, 1 ; "range-565653-565659")]
+    #[test_case("824824821-824824827", 0, 1 ; "range-824824821-824824827")]
+    #[test_case("2121212118-2121212124", 0, 1 ; "range-2121212118-2121212124")]
+    fn example(given: &str, invalid: usize, repeated: usize) {
+        let r: Interval = given.parse().unwrap();
+        let ids = r.iter().map(ID::new_with).collect::<Vec<_>>();
+        assert_eq!(ids.iter().filter(|x| x.invalid()).count(), invalid);
+        assert_eq!(ids.iter().filter(|x| x.repeated()).count(), repeated);
+    }
+
+    #[test]
+    fn short() {
+        assert!(!same(1, &['7']));
+        assert!(!same(0, &[]));
+        assert!(same(1, &['7', '7']));
+        assert!(!ID::new_with(7).invalid());
+        assert!(!ID::new_with(7).repeated());
+    }
+
+    #[test]
+    fn ranges() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = IDRanges::from_str(input).unwrap();
+        assert_eq!(ranges.part1(), 1227775554);
+        assert_eq!(ranges.part2(), 4174379265);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            IDRanges::from_str("11-22,95115").err(),
+            Some(ParseError::new(7, "95115", "a range (start-end)"))
+        );
+        assert_eq!(
+            IDRanges::from_str("11-22,95-1x5").err(),
+            Some(ParseError::new(10, "1x5", "a number"))
+        );
+    }
+}
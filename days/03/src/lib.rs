@@ -0,0 +1,161 @@
+use std::{ops::Range, str::FromStr};
+
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{chars, lines},
+};
+
+pub struct Banks(Vec<Bank>);
+pub struct Bank(Vec<usize>);
+
+impl FromStr for Banks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Banks(lines(s, Bank::from_str)?))
+    }
+}
+
+impl Banks {
+    pub fn total_joltage(&self, batteries: usize) -> usize {
+        self.0.iter().map(|bank| bank.joltage(batteries)).sum()
+    }
+
+    pub fn part1(&self) -> usize {
+        self.total_joltage(2)
+    }
+
+    pub fn part2(&self) -> usize {
+        self.total_joltage(12)
+    }
+}
+
+impl FromStr for Bank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = chars(s, "a battery joltage digit", |c| c.to_digit(10))?;
+        Ok(Bank(digits.into_iter().map(|x| x as usize).collect()))
+    }
+}
+
+/// index of the first highest digit in `range`
+pub fn highest(range: Range<usize>, digits: &[usize]) -> usize {
+    assert!((0..=digits.len()).contains(&range.start));
+    assert!((0..=digits.len()).contains(&range.end));
+    let mut j = range.start;
+    if digits[j] == 9 {
+        return j;
+    }
+    for i in j + 1..range.end {
+        if digits[i] == 9 {
+            return i;
+        }
+        if digits[i] > digits[j] {
+            j = i;
+        }
+    }
+    j
+}
+
+impl Bank {
+    /// indexes of the `batteries` turned on for the highest joltage,
+    /// each one the highest leaving enough batteries after it
+    pub fn highest_indexes(&self, batteries: usize) -> Vec<usize> {
+        let len = self.0.len();
+        let mut indexes = Vec::with_capacity(batteries);
+        let mut start = 0;
+        for left in (0..batteries.min(len)).rev() {
+            let index = highest(start..len - left, &self.0);
+            indexes.push(index);
+            start = index + 1;
+        }
+        indexes
+    }
+
+    pub fn highest_joltage(&self, batteries: usize) -> Vec<usize> {
+        self.highest_indexes(batteries)
+            .into_iter()
+            .map(|i| self.0[i])
+            .collect()
+    }
+
+    pub fn joltage(&self, batteries: usize) -> usize {
+        self.highest_joltage(batteries)
+            .into_iter()
+            .fold(0, |acc, x| acc * 10 + x)
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "total joltage";
+
+    type Input = Banks;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Banks::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "total joltage";
+
+    type Input = Banks;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Banks::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("987654321111111", 98, 987654321111 ; "joltage-987654321111111")]
+    #[test_case("811111111111119", 89, 811111111119 ; "joltage-811111111111119")]
+    #[test_case("234234234234278", 78, 434234234278 ; "joltage-234234234234278")]
+    #[test_case("818181911112111", 92, 888911112111 ; "joltage-818181911112111")]
+    fn batteries(given: &str, two: usize, twelve: usize) {
+        let bank = Bank::from_str(given).unwrap();
+        assert_eq!(bank.joltage(2), two);
+        assert_eq!(bank.joltage(12), twelve);
+    }
+
+    #[test]
+    fn joltage() {
+        let input = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+        let banks = Banks::from_str(input).unwrap();
+        assert_eq!(banks.part1(), 357);
+        assert_eq!(banks.part2(), 3121910778619);
+    }
+
+    #[test]
+    fn invalid_digit() {
+        assert_eq!(
+            Banks::from_str("987654321111111\n81111x111111119").err(),
+            Some(ParseError::new(6, "x", "a battery joltage digit").on_line(2))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_2025_grid::{Cell, Grid, Point};
+use aoc_2025_solution::{ParseError, Solution};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Value {
+    Empty,
+    Roll,
+}
+
+impl Cell for Value {
+    const EXPECTED: &'static str = "`.` or `@`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Value::Empty),
+            '@' => Some(Value::Roll),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Value::Empty => '.',
+            Value::Roll => '@',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rolls(Grid<Value>);
+
+impl Rolls {
+    /// fewer than four rolls around it
+    pub fn accessible(&self, point: Point) -> bool {
+        self.0
+            .neighbours8(point)
+            .filter(|x| self.0[*x] == Value::Roll)
+            .count()
+            < 4
+    }
+    pub fn accessibles(&self) -> Vec<Point> {
+        self.0
+            .iter()
+            .filter(|(p, v)| **v == Value::Roll && self.accessible(*p))
+            .map(|(p, _)| p)
+            .collect()
+    }
+    pub fn count_accessibles(&self) -> usize {
+        self.accessibles().len()
+    }
+    pub fn count_recurse_accessibles(&self) -> usize {
+        let mut me = self.clone();
+        let mut total: usize = 0;
+        loop {
+            let current = me.accessibles();
+            if current.is_empty() {
+                break;
+            }
+            total += current.len();
+            for point in current {
+                me.0[point] = Value::Empty;
+            }
+        }
+        total
+    }
+
+    pub fn part1(&self) -> usize {
+        self.count_accessibles()
+    }
+
+    pub fn part2(&self) -> usize {
+        self.count_recurse_accessibles()
+    }
+}
+
+impl FromStr for Rolls {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+/// rolls accessible are marked with `x`
+impl Display for Rolls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let marked = self.0.map(|p, v| match v {
+            Value::Roll if self.accessible(p) => 'x',
+            _ => v.to_char(),
+        });
+        write!(f, "{marked}")
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "total rolls accessibles";
+
+    type Input = Rolls;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Rolls::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "total rolls accessibles recursively";
+
+    type Input = Rolls;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Rolls::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const INPUT: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+    const EXPECTED: &str = "..xx.xx@x.
+x@@.@.@.@@
+@@@@@.x.@@
+@.@@@@..@.
+x@.@@@@.@x
+.@@@@@@@.@
+.@.@.@.@@@
+x.@@@.@@@@
+.@@@@@@@@.
+x.x.@@@.x.";
+
+    #[test]
+    fn grid() {
+        let grid = Rolls::from_str(INPUT);
+        assert!(grid.is_ok());
+        let grid = grid.unwrap();
+        assert_eq!(grid.to_string(), EXPECTED);
+        assert_eq!(grid.part1(), 13);
+        assert_eq!(grid.part2(), 43);
+    }
+
+    #[test]
+    fn invalid_value() {
+        assert_eq!(
+            Rolls::from_str("..@\n.#.").err(),
+            Some(ParseError::new(2, "#", "`.` or `@`").on_line(2))
+        );
+    }
+}
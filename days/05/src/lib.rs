@@ -0,0 +1,136 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_2025_interval::{Interval, IntervalSet};
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{lines, number, sections},
+};
+
+pub struct Goods {
+    fresh: IntervalSet,
+    ingredients: HashSet<usize>,
+}
+
+impl Goods {
+    pub fn count_fresh(&self) -> usize {
+        self.ingredients
+            .iter()
+            .filter(|x| self.fresh.contains(**x))
+            .count()
+    }
+    pub fn count_fresh_ranges(&self) -> u128 {
+        self.fresh.len()
+    }
+
+    pub fn part1(&self) -> usize {
+        self.count_fresh()
+    }
+
+    pub fn part2(&self) -> u128 {
+        self.count_fresh_ranges()
+    }
+}
+
+impl FromStr for Goods {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fresh = IntervalSet::default();
+        let mut ingredients = HashSet::new();
+        // fresh ranges first, then the ingredients
+        for (i, section) in sections(s).into_iter().enumerate() {
+            let located = |e: ParseError| e.within(s, section);
+            if i == 0 {
+                let ranges = lines(section, Interval::from_str).map_err(located)?;
+                fresh = ranges.into_iter().collect();
+            } else {
+                let ids =
+                    lines(section, |x| number::<usize>(x, "an ingredient id")).map_err(located)?;
+                ingredients.extend(ids);
+            }
+        }
+        Ok(Self { fresh, ingredients })
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "fresh ingredients";
+
+    type Input = Goods;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Goods::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 5;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "fresh ingredients ranges";
+
+    type Input = Goods;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Goods::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    #[test]
+    fn fresh() {
+        let goods = Goods::from_str(INPUT).unwrap();
+        assert_eq!(goods.part1(), 3);
+        assert_eq!(goods.part2(), 14);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Goods::from_str("3-5\n10-1a").err(),
+            Some(ParseError::new(4, "1a", "a number").on_line(2))
+        );
+        assert_eq!(
+            Goods::from_str("3-5\n10\n\n1").err(),
+            Some(ParseError::new(1, "10", "a range (start-end)").on_line(2))
+        );
+        assert_eq!(
+            Goods::from_str("3-5\n\n1\n5-6").err(),
+            Some(ParseError::new(1, "5-6", "an ingredient id").on_line(4))
+        );
+        assert_eq!(
+            Goods::from_str("3-5\n14-10\n\n1").err(),
+            Some(ParseError::new(1, "14-10", "a range with its start before its end").on_line(2))
+        );
+    }
+}
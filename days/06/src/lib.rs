@@ -0,0 +1,285 @@
+use std::str::FromStr;
+
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{number, words},
+};
+
+pub struct Operation {
+    operands: Vec<usize>,
+    operator: Operator,
+}
+
+impl Operation {
+    pub fn calculate(&self) -> usize {
+        let operands = self.operands.iter().copied();
+        match self.operator {
+            Operator::Add => operands.fold(0, usize::saturating_add),
+            Operator::Mul => operands.fold(1, usize::saturating_mul),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    Add,
+    Mul,
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "*" => Ok(Self::Mul),
+            "+" => Ok(Self::Add),
+            _ => Err(ParseError::new(1, s, "an operator (+ or *)")),
+        }
+    }
+}
+
+/// Operations of the worksheet as read one way or the other,
+/// row by row for part 1 and column by column for part 2.
+pub struct Operations(Vec<Operation>);
+
+impl Operations {
+    pub fn calculate(&self) -> usize {
+        self.0.iter().map(|x| x.calculate()).sum()
+    }
+
+    /// lines of operands, and the line of operators below them,
+    /// ignoring the blank lines at the end of the worksheet
+    fn split(s: &str) -> Result<(Vec<&str>, &str), ParseError> {
+        let mut lines = s.lines().collect::<Vec<_>>();
+        while lines.last().is_some_and(|x| x.trim().is_empty()) {
+            lines.pop();
+        }
+        let Some(last) = lines.pop() else {
+            return Err(ParseError::new(1, "", "a line of operators"));
+        };
+        if lines.is_empty() {
+            return Err(ParseError::new(1, last, "operands above the operators"));
+        }
+        Ok((lines, last))
+    }
+
+    /// operands read row by row, left to right
+    pub fn rows(s: &str) -> Result<Self, ParseError> {
+        let (lines, last) = Self::split(s)?;
+        let operators = words(last, Operator::from_str).map_err(|e| e.within(s, last))?;
+        let count = operators.len();
+        let mut mixed_operands: Vec<Vec<usize>> = Vec::with_capacity(lines.len());
+        let mut operations: Vec<Operation> = Vec::with_capacity(count);
+        for line in &lines {
+            let operands =
+                words(line, |x| number(x, "an operand")).map_err(|e| e.within(s, line))?;
+            if operands.len() != count {
+                let expected = format!("{count} operands");
+                return Err(ParseError::new(1, *line, expected).within(s, line));
+            }
+            mixed_operands.push(operands);
+        }
+        for i in 0..count {
+            operations.push(Operation {
+                operands: mixed_operands.iter().map(|x| x[i]).collect(),
+                operator: operators[i],
+            });
+        }
+        Ok(Operations(operations))
+    }
+
+    /// operands read column by column, right to left, with their digits top to bottom
+    pub fn columns(s: &str) -> Result<Self, ParseError> {
+        let (lines, last) = Self::split(s)?;
+        let lines_count = lines.len() + 1;
+        let line_len = last.chars().count();
+        let mut operands: Vec<usize> = vec![];
+        let mut operations = vec![];
+        let mut digit;
+        let mut skip = false;
+        for (char_idx, operator) in last.chars().rev().enumerate() {
+            if skip {
+                operands = vec![];
+                skip = false;
+                continue;
+            }
+            let column = line_len - char_idx;
+            digit = String::new();
+            for (y, line) in lines.iter().enumerate() {
+                let Some(char) = line.chars().nth_back(char_idx) else {
+                    let width = format!("{line_len} columns");
+                    return Err(ParseError::new(1, *line, width).on_line(y + 1));
+                };
+                if char.is_ascii_digit() {
+                    digit.push(char);
+                }
+            }
+            if !digit.is_empty() {
+                operands.push(number(&digit, "an operand").map_err(|e| e.shifted(column - 1))?);
+            }
+            let operator = match operator {
+                '+' => Operator::Add,
+                '*' => Operator::Mul,
+                ' ' => continue,
+                c => {
+                    return Err(
+                        ParseError::new(column, c, "an operator (+ or *)").on_line(lines_count)
+                    );
+                }
+            };
+            if operands.is_empty() {
+                return Err(
+                    ParseError::new(column, "", "operands above the operator").on_line(lines_count)
+                );
+            }
+            operations.push(Operation {
+                operands: operands.to_vec(),
+                operator,
+            });
+            skip = true;
+        }
+        let operations = Operations(operations);
+        Ok(operations)
+    }
+}
+
+/// The worksheet as written, read one way or the other only once a part asks for it.
+pub struct Worksheet(String);
+
+impl Worksheet {
+    pub fn part1(&self) -> Result<usize, ParseError> {
+        Operations::rows(&self.0).map(|x| x.calculate())
+    }
+
+    pub fn part2(&self) -> Result<usize, ParseError> {
+        Operations::columns(&self.0).map(|x| x.calculate())
+    }
+}
+
+impl FromStr for Worksheet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Operations::split(s)?;
+        Ok(Self(s.to_string()))
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "cephalopod math";
+
+    type Input = Worksheet;
+    type Answer = Result<usize, ParseError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Worksheet::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "big cephalopod math";
+
+    type Input = Worksheet;
+    type Answer = Result<usize, ParseError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Worksheet::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // because IDE will constantly remove trailing spaces...
+    const INPUT: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+
+    #[test]
+    fn example() {
+        let worksheet = Worksheet::from_str(INPUT).unwrap();
+        assert_eq!(worksheet.part1(), Ok(4277556));
+        assert_eq!(worksheet.part2(), Ok(3263827));
+        let trailing = Worksheet::from_str("12 3\n4  5\n+  *\n\n").unwrap();
+        assert_eq!(trailing.part1(), Ok(31));
+        assert_eq!(trailing.part2(), Ok(51));
+    }
+
+    #[test]
+    fn uneven_rows() {
+        let input = r#"123 328  51 64
+         45 64  387 23
+          6 98  215 314
+        *   +   *   + "#;
+        let operations = Operations::rows(input).unwrap();
+        assert_eq!(operations.calculate(), 4277556);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Operations::rows("1 2\n3 4\n* -").err(),
+            Some(ParseError::new(3, "-", "an operator (+ or *)").on_line(3))
+        );
+        assert_eq!(
+            Operations::rows("1 2\n3 x\n* +").err(),
+            Some(ParseError::new(3, "x", "an operand").on_line(2))
+        );
+        assert_eq!(
+            Operations::rows("1 2\n3\n* +").err(),
+            Some(ParseError::new(1, "3", "2 operands").on_line(2))
+        );
+        assert_eq!(
+            Operations::columns("12 3\n45 6\n*  -").err(),
+            Some(ParseError::new(4, "-", "an operator (+ or *)").on_line(3))
+        );
+        assert_eq!(
+            Operations::columns("12 3\n45\n*  +").err(),
+            Some(ParseError::new(1, "45", "4 columns").on_line(2))
+        );
+        assert_eq!(
+            Operations::rows("\n \n").err(),
+            Some(ParseError::new(1, "", "a line of operators"))
+        );
+        assert_eq!(
+            Operations::columns("+  *\n\n").err(),
+            Some(ParseError::new(1, "+  *", "operands above the operators"))
+        );
+        assert_eq!(
+            Operations::rows("12 3\n4\n+  *\n\n").err(),
+            Some(ParseError::new(1, "4", "2 operands").on_line(2))
+        );
+        assert_eq!(
+            Operations::columns("12 3\n4  x\n+  -\n\n").err(),
+            Some(ParseError::new(4, "-", "an operator (+ or *)").on_line(3))
+        );
+        assert_eq!(
+            Part1::run("+  *\n\n").err(),
+            Some(ParseError::new(1, "+  *", "operands above the operators"))
+        );
+        let worksheet = Worksheet::from_str("12 3\n45\n*  +").unwrap();
+        assert_eq!(
+            worksheet.part1(),
+            Err(ParseError::new(1, "45", "2 operands").on_line(2))
+        );
+        assert_eq!(
+            worksheet.part2(),
+            Err(ParseError::new(1, "45", "4 columns").on_line(2))
+        );
+    }
+}
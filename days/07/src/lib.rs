@@ -0,0 +1,194 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use aoc_2025_grid::{Cell, Grid, Point};
+use aoc_2025_solution::{ParseError, Solution};
+
+#[derive(Debug, PartialEq)]
+pub enum Value {
+    Empty,
+    Start,
+    Splitter,
+}
+
+impl Cell for Value {
+    const EXPECTED: &'static str = "`.`, `S` or `^`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Value::Empty),
+            'S' => Some(Value::Start),
+            '^' => Some(Value::Splitter),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Value::Empty => '.',
+            Value::Start => 'S',
+            Value::Splitter => '^',
+        }
+    }
+}
+
+pub struct Manifold {
+    grid: Grid<Value>,
+    start: Point,
+}
+
+impl Manifold {
+    /// times a beam is split on its way down
+    pub fn count_splits(&self) -> usize {
+        let mut beams = BTreeSet::from([self.start.x]);
+        let mut splits = 0;
+        for y in self.start.y + 1..self.grid.height() {
+            let mut next = BTreeSet::new();
+            for x in beams {
+                match self.grid.get(Point::new(x, y)) {
+                    Some(Value::Splitter) => {
+                        splits += 1;
+                        next.extend(x.checked_sub(1));
+                        next.insert(x + 1);
+                    }
+                    Some(_) => {
+                        next.insert(x);
+                    }
+                    // the beam left the manifold on the side
+                    None => (),
+                }
+            }
+            beams = next;
+        }
+        splits
+    }
+
+    /// Timelines a single particle ends up in, one more each time one of them is split.
+    /// Timelines reaching the same place are counted together, rather than followed one by one.
+    pub fn count_timelines(&self) -> usize {
+        let mut timelines = BTreeMap::from([(self.start.x, 1)]);
+        let mut ended = 0;
+        for y in self.start.y + 1..self.grid.height() {
+            let mut next = BTreeMap::new();
+            for (x, count) in timelines {
+                match self.grid.get(Point::new(x, y)) {
+                    Some(Value::Splitter) => {
+                        match x.checked_sub(1) {
+                            Some(left) => *next.entry(left).or_default() += count,
+                            None => ended += count,
+                        }
+                        *next.entry(x + 1).or_default() += count;
+                    }
+                    Some(_) => *next.entry(x).or_default() += count,
+                    // the particle left the manifold on the side
+                    None => ended += count,
+                }
+            }
+            timelines = next;
+        }
+        ended + timelines.values().sum::<usize>()
+    }
+
+    pub fn part1(&self) -> usize {
+        self.count_splits()
+    }
+
+    pub fn part2(&self) -> usize {
+        self.count_timelines()
+    }
+}
+
+impl FromStr for Manifold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::<Value>::from_str(s)?;
+        let starts = grid
+            .iter()
+            .filter(|(_, v)| **v == Value::Start)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        let start = match starts[..] {
+            [] => return Err(ParseError::new(1, "", "a start `S`").on_line(grid.height().max(1))),
+            [start] => start,
+            [_, other, ..] => {
+                return Err(
+                    ParseError::new(other.x + 1, "S", "`.` or `^` after the start")
+                        .on_line(other.y + 1),
+                );
+            }
+        };
+        Ok(Self { grid, start })
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 7;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "beam splits";
+
+    type Input = Manifold;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Manifold::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "timelines";
+
+    type Input = Manifold;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Manifold::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test_case(EXAMPLE, 21, 40 ; "example")]
+    #[test_case("..S..\n.....\n..^..\n.^.^.\n.....", 3, 4 ; "merged beams")]
+    #[test_case("S.\n^.\n..", 1, 2 ; "edge")]
+    fn example(given: &str, splits: usize, timelines: usize) {
+        assert_eq!(Part1::run(given), Ok(splits));
+        assert_eq!(Part2::run(given), Ok(timelines));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Manifold::from_str("..S\n.#.").err(),
+            Some(ParseError::new(2, "#", "`.`, `S` or `^`").on_line(2))
+        );
+        assert_eq!(
+            Manifold::from_str("..S\n.S.").err(),
+            Some(ParseError::new(2, "S", "`.` or `^` after the start").on_line(2))
+        );
+        assert_eq!(
+            Manifold::from_str("...\n.^.").err(),
+            Some(ParseError::new(1, "", "a start `S`").on_line(2))
+        );
+    }
+}
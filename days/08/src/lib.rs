@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{lines, list, number},
+};
+
+/// pairs connected on the real input
+pub const CONNECTIONS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        Point { x, y, z }
+    }
+
+    /// square of the euclidean distance, which sorts the same
+    #[inline]
+    pub fn distance(&self, other: &Point) -> u64 {
+        [
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        ]
+        .iter()
+        .map(|x| (*x as u64).pow(2))
+        .sum()
+    }
+}
+
+/// Disjoint sets of `0..len`, merged by size with path halving.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    /// merge the sets of `a` and `b`, false when they already were the same
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (large, small) = match self.sizes[a] >= self.sizes[b] {
+            true => (a, b),
+            false => (b, a),
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+        true
+    }
+
+    /// how many disjoint sets are left
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// sizes of every set
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.parents.len())
+            .filter(|i| self.parents[*i] == *i)
+            .map(|i| self.sizes[i])
+            .collect()
+    }
+}
+
+pub struct Boxes(Vec<Point>);
+
+impl Boxes {
+    /// Every pair of boxes, closest first, as `(distance, a, b)`.
+    /// Only the `limit` closest pairs are sorted when given, the rest being dropped.
+    pub fn pairs(&self, limit: Option<usize>) -> Vec<(u64, usize, usize)> {
+        let mut pairs = Vec::with_capacity(self.0.len() * self.0.len().saturating_sub(1) / 2);
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in self.0.iter().enumerate().skip(i + 1) {
+                pairs.push((a.distance(b), i, j));
+            }
+        }
+        if let Some(limit) = limit.filter(|x| *x < pairs.len()) {
+            pairs.select_nth_unstable(limit);
+            pairs.truncate(limit);
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// product of the sizes of the three largest circuits, once the `n` closest pairs connected
+    pub fn largest_circuits(&self, n: usize) -> usize {
+        let mut circuits = DisjointSet::new(self.0.len());
+        for (_, a, b) in self.pairs(Some(n)) {
+            circuits.union(a, b);
+        }
+        let mut sizes = circuits.sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.iter().take(3).product()
+    }
+
+    /// Product of the x coordinates of the last two boxes connected,
+    /// when connecting the closest pairs first until a single circuit is left.
+    pub fn last_connection(&self) -> Option<usize> {
+        let mut circuits = DisjointSet::new(self.0.len());
+        for (_, a, b) in self.pairs(None) {
+            if circuits.union(a, b) && circuits.count() == 1 {
+                return Some(self.0[a].x * self.0[b].x);
+            }
+        }
+        None
+    }
+
+    pub fn part1(&self) -> usize {
+        self.largest_circuits(CONNECTIONS)
+    }
+
+    pub fn part2(&self) -> usize {
+        // a single box is already a single circuit, without any connection
+        self.last_connection().unwrap_or(0)
+    }
+}
+
+impl FromStr for Boxes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let boxes = lines(s, |line| {
+            let coordinates = list(line, ',', |x| number(x, "a coordinate"))?;
+            let [x, y, z] = coordinates[..] else {
+                return Err(ParseError::new(1, line, "a junction box (x,y,z)"));
+            };
+            Ok(Point::new(x, y, z))
+        })?;
+        Ok(Self(boxes))
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 8;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "largest circuits";
+
+    type Input = Boxes;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Boxes::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "last connection";
+
+    type Input = Boxes;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Boxes::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test_case(EXAMPLE, 10, 40 ; "example")]
+    #[test_case(EXAMPLE, 1, 2 ; "single connection")]
+    #[test_case("0,0,0\n1,0,0\n5,0,0\n9,9,9", 10, 4 ; "fewer pairs")]
+    fn example(given: &str, connections: usize, expected: usize) {
+        let boxes = Boxes::from_str(given).unwrap();
+        assert_eq!(boxes.largest_circuits(connections), expected);
+    }
+
+    #[test_case(EXAMPLE, 25272 ; "example")]
+    #[test_case("2,0,0\n3,0,0\n7,0,0\n20,9,9", 140 ; "outlier")]
+    #[test_case("2,0,0", 0 ; "single box")]
+    fn last_connection(given: &str, expected: usize) {
+        assert_eq!(Part2::run(given), Ok(expected));
+    }
+
+    #[test]
+    fn pairs() {
+        let boxes = Boxes::from_str(EXAMPLE).unwrap();
+        let pairs = boxes.pairs(None);
+        assert_eq!(pairs.len(), 190);
+        assert!(pairs.is_sorted());
+        assert_eq!(boxes.pairs(Some(10))[..], pairs[..10]);
+        // 162,817,812 and 425,690,689 are the closest
+        assert_eq!((pairs[0].1, pairs[0].2), (0, 19));
+    }
+
+    #[test]
+    fn disjoint_set() {
+        let mut set = DisjointSet::new(5);
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 4));
+        assert!(set.union(1, 4));
+        assert!(!set.union(0, 3));
+        assert_eq!(set.find(0), set.find(4));
+        assert_ne!(set.find(0), set.find(2));
+        assert_eq!(set.count(), 2);
+        let mut sizes = set.sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 4]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Boxes::from_str("1,2,3\n4,5").err(),
+            Some(ParseError::new(1, "4,5", "a junction box (x,y,z)").on_line(2))
+        );
+        assert_eq!(
+            Boxes::from_str("1,2,3\n4,5,6,7").err(),
+            Some(ParseError::new(1, "4,5,6,7", "a junction box (x,y,z)").on_line(2))
+        );
+        assert_eq!(
+            Boxes::from_str("1,2,3\n4,x,6").err(),
+            Some(ParseError::new(3, "x", "a coordinate").on_line(2))
+        );
+    }
+}
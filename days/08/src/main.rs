@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use aoc_2025_08::{Part1, Part2};
+use aoc_2025_solution::Entry;
+
+fn main() -> ExitCode {
+    aoc_2025_solution::main(&[Entry::of::<Part1>(), Entry::of::<Part2>()])
+}
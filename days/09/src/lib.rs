@@ -0,0 +1,401 @@
+use std::str::FromStr;
+
+use aoc_2025_grid::{self as grid, Cell, Grid};
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{lines, pair},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    None,
+    Red,
+    Green,
+}
+
+impl Cell for Color {
+    const EXPECTED: &'static str = "`.`, `#` or `X`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Color::None),
+            '#' => Some(Color::Red),
+            'X' => Some(Color::Green),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Color::None => '.',
+            Color::Red => '#',
+            Color::Green => 'X',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub color: Color,
+    pub point: Point,
+}
+
+impl Tile {
+    pub fn red(x: usize, y: usize) -> Self {
+        Tile {
+            color: Color::Red,
+            point: Point { x, y },
+        }
+    }
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+}
+
+pub trait Rectangle {
+    type Rhs;
+    fn width(&self, other: &Self::Rhs) -> usize;
+    fn height(&self, other: &Self::Rhs) -> usize;
+    fn area(&self, other: &Self::Rhs) -> usize;
+}
+
+impl Rectangle for Point {
+    type Rhs = Point;
+    #[inline]
+    fn width(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x) + 1
+    }
+    #[inline]
+    fn height(&self, other: &Point) -> usize {
+        self.y.abs_diff(other.y) + 1
+    }
+    #[inline]
+    fn area(&self, other: &Point) -> usize {
+        self.width(other) * self.height(other)
+    }
+}
+
+impl Rectangle for Tile {
+    type Rhs = Tile;
+    #[inline]
+    fn width(&self, other: &Tile) -> usize {
+        self.point.width(&other.point)
+    }
+    #[inline]
+    fn height(&self, other: &Tile) -> usize {
+        self.point.height(&other.point)
+    }
+    #[inline]
+    fn area(&self, other: &Tile) -> usize {
+        self.point.area(&other.point)
+    }
+}
+
+/// Red tiles, in loop order when each one is joined to the next by a straight line of green tiles.
+pub struct Tiles(Vec<Tile>);
+
+impl Tiles {
+    fn red(&self) -> Vec<&Tile> {
+        self.0.iter().filter(|x| x.color == Color::Red).collect()
+    }
+
+    /// largest rectangle between two red tiles
+    pub fn largest_area(&self) -> usize {
+        let red = self.red();
+        let mut max = 0;
+        for (i, left) in red.iter().enumerate() {
+            for right in red[i + 1..].iter() {
+                max = max.max(left.area(right));
+            }
+        }
+        max
+    }
+
+    /// largest rectangle between two red tiles, covering only red or green tiles of the loop
+    pub fn largest_area_inside(&self) -> usize {
+        let floor = Floor::new(&self.0);
+        let red = self.red();
+        let mut max = 0;
+        for (i, left) in red.iter().enumerate() {
+            for right in red[i + 1..].iter() {
+                let area = left.area(right);
+                if area > max && floor.inside(&left.point, &right.point) {
+                    max = area;
+                }
+            }
+        }
+        max
+    }
+
+    pub fn part1(&self) -> usize {
+        self.largest_area()
+    }
+
+    pub fn part2(&self) -> usize {
+        self.largest_area_inside()
+    }
+}
+
+/// Distinct coordinates of the red tiles along one axis, each one a cell of the compressed floor,
+/// with a cell for the range in between two of them unless they are next to each other,
+/// and one on each side for the ranges around them.
+struct Axis {
+    coordinates: Vec<usize>,
+    /// cell of each coordinate
+    cells: Vec<usize>,
+    len: usize,
+}
+
+impl Axis {
+    fn new(mut coordinates: Vec<usize>) -> Self {
+        coordinates.sort_unstable();
+        coordinates.dedup();
+        let mut cells = Vec::with_capacity(coordinates.len());
+        let mut next = 1;
+        for (i, x) in coordinates.iter().enumerate() {
+            if i > 0 && x - coordinates[i - 1] > 1 {
+                next += 1;
+            }
+            cells.push(next);
+            next += 1;
+        }
+        Self {
+            coordinates,
+            cells,
+            len: next + 1,
+        }
+    }
+
+    fn cell(&self, coordinate: usize) -> usize {
+        self.cells[self.coordinates.partition_point(|x| *x < coordinate)]
+    }
+}
+
+/// Floor with compressed coordinates, see [`Axis`].
+/// Every cell is then either entirely red or green, or entirely outside the loop.
+struct Floor {
+    xs: Axis,
+    ys: Axis,
+    /// tiles outside the loop in the cells above and on the left of each cell, exclusive
+    outside: Vec<usize>,
+}
+
+impl Floor {
+    fn new(tiles: &[Tile]) -> Self {
+        let red = tiles
+            .iter()
+            .filter(|x| x.color == Color::Red)
+            .map(|x| x.point)
+            .collect::<Vec<_>>();
+        let mut floor = Self {
+            xs: Axis::new(red.iter().map(|x| x.x).collect()),
+            ys: Axis::new(red.iter().map(|x| x.y).collect()),
+            outside: vec![],
+        };
+        let (width, height) = (floor.xs.len, floor.ys.len);
+        let mut colors = Grid::filled(width, height, Color::None);
+        for (i, from) in red.iter().enumerate() {
+            let to = red[(i + 1) % red.len()];
+            let (x1, y1) = floor.cell(from);
+            let (x2, y2) = floor.cell(&to);
+            for y in y1.min(y2)..=y1.max(y2) {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    colors[grid::Point::new(x, y)] = Color::Green;
+                }
+            }
+        }
+        for point in red.iter() {
+            let (x, y) = floor.cell(point);
+            colors[grid::Point::new(x, y)] = Color::Red;
+        }
+        // anything the outer border cannot reach is inside the loop
+        let mut reached = Grid::filled(width, height, false);
+        let corner = grid::Point::new(0, 0);
+        let mut stack = vec![corner];
+        reached[corner] = true;
+        while let Some(point) = stack.pop() {
+            for next in colors.neighbours4(point) {
+                if !reached[next] && colors[next] == Color::None {
+                    reached[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        floor.outside = vec![0; (width + 1) * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                let cell = usize::from(reached[grid::Point::new(x, y)]);
+                floor.outside[(y + 1) * (width + 1) + x + 1] = cell
+                    + floor.outside[y * (width + 1) + x + 1]
+                    + floor.outside[(y + 1) * (width + 1) + x]
+                    - floor.outside[y * (width + 1) + x];
+            }
+        }
+        floor
+    }
+
+    fn cell(&self, point: &Point) -> (usize, usize) {
+        (self.xs.cell(point.x), self.ys.cell(point.y))
+    }
+
+    /// whether the rectangle between `a` and `b` only covers red or green tiles
+    fn inside(&self, a: &Point, b: &Point) -> bool {
+        let width = self.xs.len + 1;
+        let (x1, y1) = self.cell(a);
+        let (x2, y2) = self.cell(b);
+        let (left, right) = (x1.min(x2), x1.max(x2) + 1);
+        let (top, bottom) = (y1.min(y2), y1.max(y2) + 1);
+        let at = |x: usize, y: usize| self.outside[y * width + x];
+        at(right, bottom) + at(left, top) == at(left, bottom) + at(right, top)
+    }
+}
+
+impl Tiles {
+    /// red tiles forming a loop of horizontal and vertical lines, closing on the first tile
+    pub fn closed(s: &str) -> Result<Self, ParseError> {
+        let tiles = lines(s, |line| Ok((line, tile(line)?)))?;
+        for (j, (line, tile)) in tiles.iter().enumerate() {
+            let (_, next) = tiles[(j + 1) % tiles.len()];
+            if tile.point.x != next.point.x && tile.point.y != next.point.y {
+                let expected = match j + 1 == tiles.len() {
+                    true => "a tile in line with the first one",
+                    false => "a tile in line with the next one",
+                };
+                return Err(ParseError::new(1, *line, expected).within(s, line));
+            }
+        }
+        Ok(Self(tiles.into_iter().map(|(_, x)| x).collect()))
+    }
+}
+
+/// `x,y` red tile
+fn tile(line: &str) -> Result<Tile, ParseError> {
+    let (x, y) = pair(line, ',', "a tile (x,y)", "a coordinate")?;
+    Ok(Tile::red(x, y))
+}
+
+impl FromStr for Tiles {
+    type Err = ParseError;
+
+    /// red tiles in any order, see [`Tiles::closed`] for a loop
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(lines(s, tile)?))
+    }
+}
+
+/// Drawing of the floor, `#` for red tiles and `X` for green ones.
+pub type TilesDisplay = Grid<Color>;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "largest area";
+
+    type Input = Tiles;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Tiles::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "largest area inside";
+
+    type Input = Tiles;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Tiles::closed(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn parse() {
+        let input = ".#X\n#.#";
+        let tiles = TilesDisplay::from_str(input).unwrap();
+        let colors = tiles.iter().map(|(_, x)| *x).collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            [
+                Color::None,
+                Color::Red,
+                Color::Green,
+                Color::Red,
+                Color::None,
+                Color::Red
+            ]
+        );
+        assert_eq!(tiles.to_string(), input);
+    }
+
+    #[test]
+    fn area() {
+        let a = Point { x: 11, y: 1 };
+        let b = Point { x: 2, y: 5 };
+        assert_eq!(a.area(&b), 50);
+    }
+
+    #[test_case(EXAMPLE, 50, 24 ; "example")]
+    #[test_case("1,1\n5,1\n5,5\n1,5", 25, 25 ; "square")]
+    #[test_case("0,0\n9,0\n9,9\n6,9\n6,2\n3,2\n3,9\n0,9", 100, 40 ; "u shape")]
+    #[test_case("0,0\n9,0\n9,9\n4,9\n4,2\n3,2\n3,9\n0,9", 100, 100 ; "u shape without a gap")]
+    fn example(given: &str, area: usize, inside: usize) {
+        assert_eq!(Part1::run(given), Ok(area));
+        assert_eq!(Part2::run(given), Ok(inside));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Tiles::from_str("7,1\n11;1").err(),
+            Some(ParseError::new(1, "11;1", "a tile (x,y)").on_line(2))
+        );
+        assert_eq!(
+            Tiles::from_str("7,1\n11,-1").err(),
+            Some(ParseError::new(4, "-1", "a coordinate").on_line(2))
+        );
+        assert_eq!(Part1::run("7,1\n11,2\n11,7"), Ok(35));
+        assert_eq!(
+            Part2::run("7,1\n11,2\n11,7").err(),
+            Some(ParseError::new(1, "7,1", "a tile in line with the next one").on_line(1))
+        );
+        assert_eq!(
+            Tiles::closed("7,1\n11,1\n11,7").err(),
+            Some(ParseError::new(1, "11,7", "a tile in line with the first one").on_line(3))
+        );
+        assert_eq!(
+            TilesDisplay::from_str(".#.\n#o#").err(),
+            Some(ParseError::new(2, "o", "`.`, `#` or `X`").on_line(2))
+        );
+    }
+}
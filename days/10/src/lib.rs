@@ -0,0 +1,519 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_2025_solution::{
+    ParseError, Solution, column,
+    parse::{lines, list, number},
+};
+
+/// Lights and buttons are bit masks, hence at most 64 of each.
+pub const MAX: usize = 64;
+
+/// Free buttons are tried in every combination, hence at most 2^20 of them.
+pub const MAX_FREE: usize = 20;
+
+/// Why a machine has no fewest presses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unsolvable {
+    Lights,
+    Joltages,
+    /// more free buttons than [`MAX_FREE`]
+    TooManyFree(usize),
+}
+
+impl std::fmt::Display for Unsolvable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unsolvable::Lights => write!(f, "no presses turn the lights on"),
+            Unsolvable::Joltages => write!(f, "no presses reach the joltages"),
+            Unsolvable::TooManyFree(x) => {
+                write!(f, "{x} free buttons, at most {MAX_FREE} can be tried")
+            }
+        }
+    }
+}
+
+/// Machine of the input without any fewest presses, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unsolved {
+    pub machine: usize,
+    pub reason: Unsolvable,
+}
+
+impl std::fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "machine {}: {}", self.machine, self.reason)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Machine {
+    /// lights to turn on, bit `i` for light `i`
+    pub lights: u64,
+    /// lights toggled by each button
+    pub buttons: Vec<u64>,
+    /// one per light
+    pub joltages: Vec<u32>,
+}
+
+/// Buttons as the columns of a system over GF(2), one equation per light,
+/// reduced once then solved for any lights to turn on.
+#[derive(Debug, Clone)]
+pub struct Toggles {
+    /// reduced equations, as the buttons involved, the pivot first,
+    /// and the original equations they combine
+    rows: Vec<(usize, u64, u64)>,
+    /// original equations combined into nothing, which must not add up to any light
+    empty: Vec<u64>,
+    /// buttons free to be pressed or not
+    free: Vec<usize>,
+}
+
+impl Toggles {
+    pub fn new(machine: &Machine) -> Self {
+        let lights = machine.joltages.len();
+        let mut equations = (0..lights)
+            .map(|light| {
+                let buttons = machine
+                    .buttons
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, x)| *x & (1 << light) != 0)
+                    .fold(0u64, |acc, (i, _)| acc | 1 << i);
+                (buttons, 1u64 << light)
+            })
+            .collect::<Vec<_>>();
+        let mut rows = vec![];
+        let mut free = vec![];
+        for button in 0..machine.buttons.len() {
+            let bit = 1 << button;
+            let Some(found) = equations.iter().position(|(x, _)| x & bit != 0) else {
+                free.push(button);
+                continue;
+            };
+            let (pivot, combined) = equations.swap_remove(found);
+            for (x, c) in equations.iter_mut() {
+                if *x & bit != 0 {
+                    *x ^= pivot;
+                    *c ^= combined;
+                }
+            }
+            for (_, x, c) in rows.iter_mut() {
+                if *x & bit != 0 {
+                    *x ^= pivot;
+                    *c ^= combined;
+                }
+            }
+            rows.push((button, pivot, combined));
+        }
+        let empty = equations.into_iter().map(|(_, c)| c).collect();
+        Self { rows, empty, free }
+    }
+
+    /// Every set of buttons turning on exactly `lights`, as bit masks, none if impossible,
+    /// unless there are more than [`MAX_FREE`] free buttons to try.
+    pub fn solutions(&self, lights: u64) -> Result<impl Iterator<Item = u64> + '_, Unsolvable> {
+        if self.free.len() > MAX_FREE {
+            return Err(Unsolvable::TooManyFree(self.free.len()));
+        }
+        let parity = move |x: u64| (x & lights).count_ones() % 2 == 1;
+        let possible = !self.empty.iter().any(|x| parity(*x));
+        let count = if possible { 1u64 << self.free.len() } else { 0 };
+        Ok((0..count).map(move |assignment| {
+            let mut pressed = self
+                .free
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| assignment & (1 << i) != 0)
+                .fold(0u64, |acc, (_, button)| acc | 1 << button);
+            let free = pressed;
+            for (pivot, buttons, combined) in self.rows.iter() {
+                if parity(*combined) ^ ((buttons & free).count_ones() % 2 == 1) {
+                    pressed |= 1 << pivot;
+                }
+            }
+            pressed
+        }))
+    }
+}
+
+impl Machine {
+    /// fewest buttons presses turning on the lights, if any
+    pub fn fewest_presses(&self) -> Result<u32, Unsolvable> {
+        Toggles::new(self)
+            .solutions(self.lights)?
+            .map(u64::count_ones)
+            .min()
+            .ok_or(Unsolvable::Lights)
+    }
+
+    /// Fewest buttons presses increasing the joltages from zero to their requirement, if any.
+    ///
+    /// Buttons pressed an odd number of times are a solution over GF(2) for the odd joltages,
+    /// and once pressed the joltages left are all even: halving them gives the same problem,
+    /// where every press then counts twice. Solving it for every such set of buttons, and
+    /// remembering the joltages already solved, finds the fewest presses exactly.
+    pub fn fewest_presses_for_joltages(&self) -> Result<u64, Unsolvable> {
+        let toggles = Toggles::new(self);
+        self.presses(&toggles, &self.joltages, &mut HashMap::new())?
+            .ok_or(Unsolvable::Joltages)
+    }
+
+    fn presses(
+        &self,
+        toggles: &Toggles,
+        joltages: &[u32],
+        solved: &mut HashMap<Vec<u32>, Option<u64>>,
+    ) -> Result<Option<u64>, Unsolvable> {
+        if joltages.iter().all(|x| *x == 0) {
+            return Ok(Some(0));
+        }
+        if let Some(presses) = solved.get(joltages) {
+            return Ok(*presses);
+        }
+        let odd = joltages
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x % 2 == 1)
+            .fold(0u64, |acc, (i, _)| acc | 1 << i);
+        let mut fewest = None;
+        'solutions: for pressed in toggles.solutions(odd)? {
+            let mut left = joltages.to_vec();
+            for (button, lights) in self.buttons.iter().enumerate() {
+                if pressed & (1 << button) == 0 {
+                    continue;
+                }
+                for (light, joltage) in left.iter_mut().enumerate() {
+                    if lights & (1 << light) != 0 {
+                        let Some(x) = joltage.checked_sub(1) else {
+                            continue 'solutions;
+                        };
+                        *joltage = x;
+                    }
+                }
+            }
+            left.iter_mut().for_each(|x| *x /= 2);
+            if let Some(presses) = self.presses(toggles, &left, solved)? {
+                let presses = u64::from(pressed.count_ones()) + 2 * presses;
+                fewest = Some(fewest.map_or(presses, |x: u64| x.min(presses)));
+            }
+        }
+        solved.insert(joltages.to_vec(), fewest);
+        Ok(fewest)
+    }
+}
+
+/// numbers between the brackets of `token`, a word of `line`, each along its text
+fn numbers<'a>(
+    line: &str,
+    token: &'a str,
+    expected: &str,
+) -> Result<Vec<(&'a str, u32)>, ParseError> {
+    let inner = &token[1..token.len() - 1];
+    list(inner, ',', |x| number(x, expected).map(|n| (x, n))).map_err(|e| e.within(line, inner))
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut tokens = line.split_whitespace().peekable();
+        let at = |token: &str| column(line, token);
+        let lights = match tokens.next() {
+            Some(token) if token.starts_with('[') && token.ends_with(']') && token.len() > 1 => {
+                &token[1..token.len() - 1]
+            }
+            token => {
+                let token = token.unwrap_or("");
+                let column = if token.is_empty() { 1 } else { at(token) };
+                return Err(ParseError::new(column, token, "indicator lights ([.#])"));
+            }
+        };
+        if lights.len() > MAX {
+            return Err(ParseError::new(at(lights), lights, "at most 64 lights"));
+        }
+        let mut machine = Machine {
+            lights: 0,
+            buttons: vec![],
+            joltages: vec![],
+        };
+        for (i, c) in lights.chars().enumerate() {
+            match c {
+                '.' => (),
+                '#' => machine.lights |= 1 << i,
+                _ => return Err(ParseError::new(at(lights) + i, c, "`.` or `#`")),
+            }
+        }
+        while let Some(token) = tokens.next_if(|x| x.starts_with('(')) {
+            if !token.ends_with(')') {
+                return Err(ParseError::new(at(token), token, "a button (i,j,..)"));
+            }
+            if machine.buttons.len() == MAX {
+                return Err(ParseError::new(at(token), token, "at most 64 buttons"));
+            }
+            let expected = format!("a light index below {}", lights.len());
+            let mut button = 0;
+            for (text, light) in numbers(line, token, &expected)? {
+                if light as usize >= lights.len() {
+                    return Err(ParseError::new(1, text, expected).within(line, text));
+                }
+                button |= 1 << light;
+            }
+            machine.buttons.push(button);
+        }
+        match tokens.next() {
+            Some(token) if token.starts_with('{') && token.ends_with('}') => {
+                let joltages = numbers(line, token, "a joltage")?;
+                machine.joltages = joltages.into_iter().map(|(_, x)| x).collect();
+                if machine.joltages.len() != lights.len() {
+                    let expected = format!("{} joltages", lights.len());
+                    return Err(ParseError::new(at(token), token, expected));
+                }
+            }
+            token => {
+                let token = token.unwrap_or("");
+                let column = if token.is_empty() {
+                    line.len() + 1
+                } else {
+                    at(token)
+                };
+                return Err(ParseError::new(column, token, "joltages ({i,j,..})"));
+            }
+        }
+        if let Some(token) = tokens.next() {
+            return Err(ParseError::new(at(token), token, "the end of the line"));
+        }
+        Ok(machine)
+    }
+}
+
+pub struct Machines(Vec<Machine>);
+
+impl Machines {
+    /// sum of `f` over every machine, unless one of them has no fewest presses
+    fn sum<T: std::iter::Sum>(
+        &self,
+        f: impl Fn(&Machine) -> Result<T, Unsolvable>,
+    ) -> Result<T, Unsolved> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                f(x).map_err(|reason| Unsolved {
+                    machine: i + 1,
+                    reason,
+                })
+            })
+            .sum()
+    }
+
+    pub fn part1(&self) -> Result<u32, Unsolved> {
+        self.sum(Machine::fewest_presses)
+    }
+
+    pub fn part2(&self) -> Result<u64, Unsolved> {
+        self.sum(Machine::fewest_presses_for_joltages)
+    }
+}
+
+impl FromStr for Machines {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(lines(s, Machine::from_str)?))
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "fewest presses";
+
+    type Input = Machines;
+    type Answer = Result<u32, Unsolved>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Machines::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 10;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "fewest presses for joltages";
+
+    type Input = Machines;
+    type Answer = Result<u64, Unsolved>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Machines::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2025_solution::Entry;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test_case(EXAMPLE, 7, 33 ; "example")]
+    #[test_case("[...] (0) (1) {0,0,0}", 0, 0 ; "all off")]
+    fn example(given: &str, lights: u32, joltages: u64) {
+        assert_eq!(Part1::run(given), Ok(Ok(lights)));
+        assert_eq!(Part2::run(given), Ok(Ok(joltages)));
+    }
+
+    #[test]
+    fn unsolvable() {
+        let unsolved = |machine, reason| Unsolved { machine, reason };
+        let given = "[..] (0) {0,0}\n[##] (0) {1,1}";
+        let lights = unsolved(2, Unsolvable::Lights);
+        assert_eq!(Part1::run(given), Ok(Err(lights)));
+        let joltages = unsolved(2, Unsolvable::Joltages);
+        assert_eq!(Part2::run(given), Ok(Err(joltages)));
+        let buttons = (0..25).map(|_| "(0)").collect::<Vec<_>>().join(" ");
+        let given = format!("[#] {buttons} {{1}}");
+        let free = unsolved(1, Unsolvable::TooManyFree(24));
+        assert_eq!(Part1::run(&given), Ok(Err(free)));
+        assert_eq!(
+            Entry::of::<Part1>().run(&given),
+            Err("machine 1: 24 free buttons, at most 20 can be tried".to_string())
+        );
+    }
+
+    #[test_case(0, Ok(2), Ok(10))]
+    #[test_case(1, Ok(3), Ok(12))]
+    #[test_case(2, Ok(2), Ok(11))]
+    fn machine(line: usize, lights: Result<u32, Unsolvable>, joltages: Result<u64, Unsolvable>) {
+        let machine = Machine::from_str(EXAMPLE.lines().nth(line).unwrap()).unwrap();
+        assert_eq!(machine.fewest_presses(), lights);
+        assert_eq!(machine.fewest_presses_for_joltages(), joltages);
+    }
+
+    #[test]
+    fn solutions() {
+        // pressing every subset of buttons finds the same lights
+        for line in EXAMPLE.lines() {
+            let machine = Machine::from_str(line).unwrap();
+            let toggles = Toggles::new(&machine);
+            for lights in 0..1 << machine.joltages.len() {
+                let mut expected = (0..1u64 << machine.buttons.len())
+                    .filter(|pressed| {
+                        machine
+                            .buttons
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| pressed & (1 << i) != 0)
+                            .fold(0, |acc, (_, x)| acc ^ x)
+                            == lights
+                    })
+                    .collect::<Vec<_>>();
+                let mut found = toggles.solutions(lights).unwrap().collect::<Vec<_>>();
+                expected.sort();
+                found.sort();
+                assert_eq!(found, expected);
+            }
+        }
+        let machine = Machine::from_str("[##] (0) (0) {1,1}").unwrap();
+        assert_eq!(machine.fewest_presses(), Err(Unsolvable::Lights));
+    }
+
+    /// fewest presses found by trying every count of presses, button after button
+    fn brute_force(buttons: &[u64], joltages: &[u32]) -> Option<u64> {
+        let Some((button, rest)) = buttons.split_first() else {
+            return joltages.iter().all(|x| *x == 0).then_some(0);
+        };
+        let mut left = joltages.to_vec();
+        let mut fewest: Option<u64> = None;
+        for presses in 0.. {
+            if let Some(x) = brute_force(rest, &left) {
+                fewest = Some(fewest.map_or(x + presses, |y| y.min(x + presses)));
+            }
+            for (light, joltage) in left.iter_mut().enumerate() {
+                if button & (1 << light) != 0 {
+                    match joltage.checked_sub(1) {
+                        Some(x) => *joltage = x,
+                        None => return fewest,
+                    }
+                }
+            }
+            if *button == 0 {
+                return fewest;
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn exact() {
+        let mut seed = 10u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % n
+        };
+        for _ in 0..200 {
+            let lights = 1 + random(4) as usize;
+            let buttons = (0..1 + random(5))
+                .map(|_| random(1 << lights))
+                .collect::<Vec<_>>();
+            let joltages = (0..lights).map(|_| random(7) as u32).collect::<Vec<_>>();
+            let machine = Machine {
+                lights: 0,
+                buttons: buttons.clone(),
+                joltages: joltages.clone(),
+            };
+            assert_eq!(
+                machine.fewest_presses_for_joltages().ok(),
+                brute_force(&buttons, &joltages),
+                "{buttons:?} {joltages:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Machines::from_str("[.#] (1) {1,2}\n(1) {1,2}").err(),
+            Some(ParseError::new(1, "(1)", "indicator lights ([.#])").on_line(2))
+        );
+        assert_eq!(
+            Machines::from_str("[.x] (1) {1,2}").err(),
+            Some(ParseError::new(3, "x", "`.` or `#`"))
+        );
+        assert_eq!(
+            Machines::from_str("[.#] (0,2) {1,2}").err(),
+            Some(ParseError::new(9, "2", "a light index below 2"))
+        );
+        assert_eq!(
+            Machines::from_str("[.#] (0,a) {1,2}").err(),
+            Some(ParseError::new(9, "a", "a light index below 2"))
+        );
+        assert_eq!(
+            Machines::from_str("[.#] (1) {1}").err(),
+            Some(ParseError::new(10, "{1}", "2 joltages"))
+        );
+        assert_eq!(
+            Machines::from_str("[.#] (1)").err(),
+            Some(ParseError::new(9, "", "joltages ({i,j,..})"))
+        );
+        assert_eq!(
+            Machines::from_str("[.#] (1) {1,2} (0)").err(),
+            Some(ParseError::new(16, "(0)", "the end of the line"))
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_2025_solution::{ParseError, Solution, parse::lines};
+
+/// Devices and the devices their outputs are connected to, without any cycle.
+#[derive(Debug)]
+pub struct Devices {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Devices {
+    fn id(&mut self, name: &str) -> usize {
+        if let Some(id) = self.index.get(name) {
+            return *id;
+        }
+        self.names.push(name.to_string());
+        self.outputs.push(vec![]);
+        self.index.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    /// devices of a cycle, the first one repeated last, if any
+    fn cycle(&self) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            Visiting,
+            Done,
+        }
+        let mut states = vec![State::New; self.names.len()];
+        for root in 0..self.names.len() {
+            if states[root] != State::New {
+                continue;
+            }
+            // devices being visited, with the next output to follow
+            let mut stack = vec![(root, 0)];
+            states[root] = State::Visiting;
+            while let Some((device, next)) = stack.last_mut() {
+                let device = *device;
+                let Some(&output) = self.outputs[device].get(*next) else {
+                    states[device] = State::Done;
+                    stack.pop();
+                    continue;
+                };
+                *next += 1;
+                match states[output] {
+                    State::New => {
+                        states[output] = State::Visiting;
+                        stack.push((output, 0));
+                    }
+                    State::Visiting => {
+                        let start = stack.iter().position(|(x, _)| *x == output)?;
+                        let mut cycle = stack[start..].iter().map(|(x, _)| *x).collect::<Vec<_>>();
+                        cycle.push(output);
+                        return Some(cycle);
+                    }
+                    State::Done => (),
+                }
+            }
+        }
+        None
+    }
+
+    /// Paths from `from` to `to` going through every device of `through`, in any order.
+    /// Paths from a device are counted once, for each of the devices of `through` already seen.
+    pub fn count_paths(&self, from: &str, to: &str, through: &[&str]) -> u64 {
+        let (Some(from), Some(to)) = (self.index.get(from), self.index.get(to)) else {
+            return 0;
+        };
+        let Some(through) = through
+            .iter()
+            .map(|x| self.index.get(*x).copied())
+            .collect::<Option<Vec<_>>>()
+        else {
+            return 0;
+        };
+        let mut counted = HashMap::new();
+        self.paths(*from, *to, &through, 0, &mut counted)
+    }
+
+    fn paths(
+        &self,
+        device: usize,
+        to: usize,
+        through: &[usize],
+        seen: u64,
+        counted: &mut HashMap<(usize, u64), u64>,
+    ) -> u64 {
+        let seen = through
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x == device)
+            .fold(seen, |acc, (i, _)| acc | 1 << i);
+        if device == to {
+            return u64::from(seen.count_ones() as usize == through.len());
+        }
+        if let Some(count) = counted.get(&(device, seen)) {
+            return *count;
+        }
+        let count = self.outputs[device]
+            .iter()
+            .map(|x| self.paths(*x, to, through, seen, counted))
+            .sum();
+        counted.insert((device, seen), count);
+        count
+    }
+
+    pub fn part1(&self) -> u64 {
+        self.count_paths("you", "out", &[])
+    }
+
+    pub fn part2(&self) -> u64 {
+        self.count_paths("svr", "out", &["dac", "fft"])
+    }
+}
+
+impl FromStr for Devices {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut devices = Devices {
+            names: vec![],
+            index: HashMap::new(),
+            outputs: vec![],
+        };
+        // line listing the outputs of each device
+        let mut listed = HashMap::new();
+        lines(s, |line| {
+            let Some((name, outputs)) = line.split_once(':') else {
+                return Err(ParseError::new(1, line, "a device (name: outputs)"));
+            };
+            let name = name.trim();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return Err(ParseError::new(1, name, "a device name").within(line, name));
+            }
+            let device = devices.id(name);
+            if listed.insert(device, line).is_some() {
+                let error = ParseError::new(1, name, "a device not listed before");
+                return Err(error.within(line, name));
+            }
+            for output in outputs.split_whitespace() {
+                let output = devices.id(output);
+                devices.outputs[device].push(output);
+            }
+            Ok(())
+        })?;
+        if let Some(cycle) = devices.cycle() {
+            let line = listed[&cycle[0]];
+            let cycle = cycle
+                .iter()
+                .map(|x| devices.names[*x].as_str())
+                .collect::<Vec<_>>();
+            let expected = format!("no cycle, as in {}", cycle.join(" -> "));
+            return Err(ParseError::new(1, line, expected).within(s, line));
+        }
+        Ok(devices)
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "paths from you";
+
+    type Input = Devices;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Devices::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+    const LABEL: &'static str = "paths from svr through dac and fft";
+
+    type Input = Devices;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Devices::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example-2.txt");
+
+    #[test_case(EXAMPLE, 5 ; "example")]
+    #[test_case("you: out out", 2 ; "parallel")]
+    #[test_case("you: aaa\naaa: bbb", 0 ; "dead end")]
+    #[test_case("aaa: out", 0 ; "no you")]
+    fn part1(given: &str, expected: u64) {
+        assert_eq!(Part1::run(given), Ok(expected));
+    }
+
+    #[test_case(EXAMPLE_2, 2 ; "example")]
+    #[test_case("svr: dac fft\ndac: fft\nfft: out", 1 ; "dac first")]
+    #[test_case("svr: fft\nfft: dac dac\ndac: out out", 4 ; "parallel")]
+    #[test_case("svr: fft\nfft: out", 0 ; "no dac")]
+    fn part2(given: &str, expected: u64) {
+        assert_eq!(Part2::run(given), Ok(expected));
+    }
+
+    #[test]
+    fn through() {
+        let devices = Devices::from_str(EXAMPLE).unwrap();
+        assert_eq!(devices.count_paths("you", "out", &["ccc"]), 3);
+        assert_eq!(devices.count_paths("you", "out", &["ccc", "eee"]), 1);
+        assert_eq!(devices.count_paths("you", "out", &["hhh"]), 0);
+        assert_eq!(devices.count_paths("aaa", "out", &["zzz"]), 0);
+        let devices = Devices::from_str(EXAMPLE_2).unwrap();
+        assert_eq!(devices.count_paths("svr", "out", &[]), 8);
+        assert_eq!(devices.count_paths("svr", "out", &["fft"]), 4);
+        assert_eq!(devices.count_paths("svr", "out", &["fft", "dac"]), 2);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Devices::from_str("you: aaa\naaa bbb").err(),
+            Some(ParseError::new(1, "aaa bbb", "a device (name: outputs)").on_line(2))
+        );
+        assert_eq!(
+            Devices::from_str("you: aaa\n: bbb").err(),
+            Some(ParseError::new(1, "", "a device name").on_line(2))
+        );
+        assert_eq!(
+            Devices::from_str("you: aaa\nyou: bbb").err(),
+            Some(ParseError::new(1, "you", "a device not listed before").on_line(2))
+        );
+        assert_eq!(
+            Devices::from_str("you: aaa\naaa: bbb out\nbbb: aaa").err(),
+            Some(
+                ParseError::new(1, "aaa: bbb out", "no cycle, as in aaa -> bbb -> aaa").on_line(2)
+            )
+        );
+        assert_eq!(
+            Devices::from_str("you: you").err(),
+            Some(ParseError::new(1, "you: you", "no cycle, as in you -> you").on_line(1))
+        );
+    }
+}
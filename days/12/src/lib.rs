@@ -0,0 +1,562 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    str::FromStr,
+};
+
+use aoc_2025_grid::{Cell, Grid};
+use aoc_2025_solution::{
+    ParseError, Solution,
+    parse::{lines, number, sections, words},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub y: usize,
+    pub x: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+}
+
+/// Cell of a present drawing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Present {
+    Empty,
+    Cell,
+}
+
+impl Cell for Present {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Present::Empty),
+            '#' => Some(Present::Cell),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Present::Empty => '.',
+            Present::Cell => '#',
+        }
+    }
+}
+
+/// Cells of a present, in every distinct orientation, rotated or flipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    /// cells of each orientation, sorted row by row, against the top and left sides
+    orientations: Vec<Vec<Point>>,
+}
+
+impl Shape {
+    pub fn new(cells: &[Point]) -> Self {
+        let mut orientations = BTreeSet::new();
+        let mut cells = normalized(cells);
+        for _ in 0..4 {
+            let height = cells.iter().map(|p| p.y + 1).max().unwrap_or(0);
+            let width = cells.iter().map(|p| p.x + 1).max().unwrap_or(0);
+            let flipped = cells
+                .iter()
+                .map(|p| Point::new(width - 1 - p.x, p.y))
+                .collect::<Vec<_>>();
+            orientations.insert(normalized(&flipped));
+            orientations.insert(cells.clone());
+            // quarter turn clockwise
+            cells = normalized(
+                &cells
+                    .iter()
+                    .map(|p| Point::new(height - 1 - p.y, p.x))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        Self {
+            orientations: orientations.into_iter().collect(),
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.orientations.first().map_or(0, Vec::len)
+    }
+
+    /// side of the smallest square holding the present
+    pub fn side(&self) -> usize {
+        self.orientations
+            .first()
+            .into_iter()
+            .flatten()
+            .map(|p| p.x.max(p.y) + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn normalized(cells: &[Point]) -> Vec<Point> {
+    let min_x = cells.iter().map(|p| p.x).min().unwrap_or(0);
+    let min_y = cells.iter().map(|p| p.y).min().unwrap_or(0);
+    let mut cells = cells
+        .iter()
+        .map(|p| Point::new(p.x - min_x, p.y - min_y))
+        .collect::<Vec<_>>();
+    cells.sort();
+    cells
+}
+
+/// Area under a tree, and how many presents of each shape must fit in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub counts: Vec<usize>,
+}
+
+impl Region {
+    /// Whether the presents fit without overlapping, which is obvious for most regions:
+    /// either they do not have enough room, or they each have a whole square of their own.
+    /// Others are packed for real.
+    pub fn fits(&self, shapes: &[Shape]) -> bool {
+        let needed = self
+            .counts
+            .iter()
+            .zip(shapes)
+            .map(|(count, shape)| count * shape.area())
+            .sum::<usize>();
+        let area = self.width * self.height;
+        if needed > area {
+            return false;
+        }
+        let side = shapes.iter().map(Shape::side).max().unwrap_or(0);
+        let presents = self.counts.iter().sum::<usize>();
+        if side == 0 || (self.width / side) * (self.height / side) >= presents {
+            return true;
+        }
+        Packing::new(self, shapes, area - needed).search(0)
+    }
+}
+
+/// Backtracking over the first free cell, which is either covered by the first cell
+/// of a present, or left empty while there is still room to spare.
+/// Searches are cut short when more cells than that can no longer be covered,
+/// or when the same cells and presents were already left to fail.
+struct Packing {
+    /// placements of a present as `(shape, cells)`, by the first cell they cover
+    placements: Vec<Vec<(usize, Vec<u64>)>>,
+    counts: Vec<usize>,
+    /// presents left to place
+    left: usize,
+    /// bit set of the cells taken
+    taken: Vec<u64>,
+    /// cells that can still be left empty
+    slack: usize,
+    /// states left to fail, as the first free cell, the cells taken from there on,
+    /// the presents and the slack left
+    failed: HashSet<(usize, Vec<u64>, Vec<usize>, usize)>,
+}
+
+impl Packing {
+    fn new(region: &Region, shapes: &[Shape], slack: usize) -> Self {
+        // scanning along the shorter side keeps fewer cells ahead to tell states apart
+        let width = region.width.min(region.height);
+        let height = region.width.max(region.height);
+        let words = (width * height).div_ceil(64);
+        let mut placements = vec![vec![]; width * height];
+        for (i, shape) in shapes.iter().enumerate() {
+            for orientation in shape.orientations.iter() {
+                for y in 0..height {
+                    for x in 0..width {
+                        let cells = orientation
+                            .iter()
+                            .map(|p| (x + p.x, y + p.y))
+                            .filter(|(x, y)| *x < width && *y < height)
+                            .map(|(x, y)| y * width + x)
+                            .collect::<Vec<_>>();
+                        if cells.len() < orientation.len() {
+                            continue;
+                        }
+                        let mut set = vec![0; words];
+                        cells.iter().for_each(|x| set[x / 64] |= 1 << (x % 64));
+                        placements[cells[0]].push((i, set));
+                    }
+                }
+            }
+        }
+        Self {
+            placements,
+            counts: region.counts.clone(),
+            left: region.counts.iter().sum(),
+            taken: vec![0; words],
+            slack,
+            failed: HashSet::new(),
+        }
+    }
+
+    fn fits(&self, cells: &[u64]) -> bool {
+        cells.iter().zip(self.taken.iter()).all(|(a, b)| a & b == 0)
+    }
+
+    fn toggle(&mut self, cells: &[u64]) {
+        self.taken.iter_mut().zip(cells).for_each(|(a, b)| *a ^= b);
+    }
+
+    /// free cells on which no present left fits anymore
+    fn uncovered(&self, from: usize) -> u32 {
+        let mut covered = self.taken.clone();
+        for (shape, cells) in self.placements[from..].iter().flatten() {
+            if self.counts[*shape] > 0 && self.fits(cells) {
+                covered.iter_mut().zip(cells).for_each(|(a, b)| *a |= b);
+            }
+        }
+        let cells = self.placements.len();
+        covered
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                // bits past the last cell
+                let past = (64 * (i + 1)).saturating_sub(cells).min(64);
+                (!x).count_ones().saturating_sub(past as u32)
+            })
+            .sum()
+    }
+
+    fn search(&mut self, from: usize) -> bool {
+        if self.left == 0 {
+            return true;
+        }
+        let is_taken = |i: usize| self.taken[i / 64] & (1 << (i % 64)) != 0;
+        let Some(cell) = (from..self.placements.len()).find(|i| !is_taken(*i)) else {
+            return false;
+        };
+        // cells before are all taken or left empty, whichever
+        let mut taken = self.taken.clone();
+        taken[..cell / 64].fill(0);
+        taken[cell / 64] &= !((1 << (cell % 64)) - 1);
+        let state = (cell, taken, self.counts.clone(), self.slack);
+        if self.failed.contains(&state) || self.uncovered(cell) as usize > self.slack {
+            return false;
+        }
+        for i in 0..self.placements[cell].len() {
+            let (shape, cells) = &self.placements[cell][i];
+            let shape = *shape;
+            if self.counts[shape] == 0 || !self.fits(cells) {
+                continue;
+            }
+            let cells = cells.clone();
+            self.toggle(&cells);
+            self.counts[shape] -= 1;
+            self.left -= 1;
+            let found = self.search(cell + 1);
+            self.toggle(&cells);
+            self.counts[shape] += 1;
+            self.left += 1;
+            if found {
+                return true;
+            }
+        }
+        if self.slack > 0 {
+            let mut cells = vec![0; self.taken.len()];
+            cells[cell / 64] = 1 << (cell % 64);
+            self.slack -= 1;
+            self.toggle(&cells);
+            let found = self.search(cell + 1);
+            self.toggle(&cells);
+            self.slack += 1;
+            if found {
+                return true;
+            }
+        }
+        self.failed.insert(state);
+        false
+    }
+}
+
+pub struct Presents {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+impl Presents {
+    pub fn count_fitting(&self) -> usize {
+        self.regions.iter().filter(|x| x.fits(&self.shapes)).count()
+    }
+
+    pub fn part1(&self) -> usize {
+        self.count_fitting()
+    }
+}
+
+/// cells of a shape drawn with `#`
+fn shape(drawing: &str) -> Result<Vec<Point>, ParseError> {
+    let drawing = Grid::<Present>::from_str(drawing)?;
+    Ok(drawing
+        .iter()
+        .filter(|(_, x)| **x == Present::Cell)
+        .map(|(p, _)| Point::new(p.x, p.y))
+        .collect())
+}
+
+impl FromStr for Presents {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut presents = Presents {
+            shapes: vec![],
+            regions: vec![],
+        };
+        for section in sections(s) {
+            let located = |e: ParseError| e.within(s, section);
+            let (header, drawing) = section
+                .split_once('\n')
+                .unwrap_or((section, &section[section.len()..]));
+            // shapes come first, as an index followed by its drawing
+            if let Some(index) = header.strip_suffix(':') {
+                let expected = presents.shapes.len();
+                if !presents.regions.is_empty() || index.parse() != Ok(expected) {
+                    let expected = format!("region or shape {expected}");
+                    return Err(located(ParseError::new(1, index, expected)));
+                }
+                let cells = shape(drawing).map_err(|e| e.within(s, drawing))?;
+                if cells.is_empty() {
+                    let error = ParseError::new(1, "", "a shape with `#`").on_line(2);
+                    return Err(located(error));
+                }
+                presents.shapes.push(Shape::new(&cells));
+                continue;
+            }
+            let shapes = presents.shapes.len();
+            let regions = lines(section, |line| region(line, shapes)).map_err(located)?;
+            presents.regions.extend(regions);
+        }
+        Ok(presents)
+    }
+}
+
+/// `WxH: counts` line of a region, with a count for each of the `shapes`
+fn region(line: &str, shapes: usize) -> Result<Region, ParseError> {
+    let Some((size, counts)) = line.split_once(':') else {
+        return Err(ParseError::new(1, line, "a region (WxH: counts)"));
+    };
+    let Some((width, height)) = size.split_once('x') else {
+        return Err(ParseError::new(1, size, "a size (WxH)"));
+    };
+    let located = |part: &str, expected: &str| {
+        number::<usize>(part, expected).map_err(|e| e.within(line, part))
+    };
+    let width = located(width, "a width")?;
+    let height = located(height, "a height")?;
+    let text = counts.trim();
+    let counts =
+        words(text, |x| number(x, "a count of presents")).map_err(|e| e.within(line, text))?;
+    if counts.len() != shapes {
+        let expected = format!("{shapes} counts of presents");
+        return Err(ParseError::new(1, text, expected).within(line, text));
+    }
+    Ok(Region {
+        width,
+        height,
+        counts,
+    })
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+    const LABEL: &'static str = "regions fitting their presents";
+
+    type Input = Presents;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Presents::from_str(input)
+    }
+
+    fn solve(input: Self::Input) -> Self::Answer {
+        input.part1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test_case(EXAMPLE, 2 ; "example")]
+    fn example(given: &str, expected: usize) {
+        assert_eq!(Part1::run(given), Ok(expected));
+    }
+
+    #[test_case(0, true)]
+    #[test_case(1, true)]
+    #[test_case(2, false)]
+    fn region(index: usize, expected: bool) {
+        let presents = Presents::from_str(EXAMPLE).unwrap();
+        assert_eq!(presents.regions[index].fits(&presents.shapes), expected);
+    }
+
+    #[test]
+    fn orientations() {
+        let presents = Presents::from_str(EXAMPLE).unwrap();
+        let counts = presents
+            .shapes
+            .iter()
+            .map(|x| x.orientations.len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![8, 8, 2, 4, 4, 2]);
+        let square = Shape::new(&[
+            Point::new(3, 3),
+            Point::new(4, 3),
+            Point::new(3, 4),
+            Point::new(4, 4),
+        ]);
+        assert_eq!(square.orientations.len(), 1);
+        assert_eq!(square.area(), 4);
+    }
+
+    /// whether the presents fit, by plain backtracking over the first free cell
+    fn brute_force(
+        taken: &mut Grid<bool>,
+        counts: &mut [usize],
+        shapes: &[Shape],
+        slack: usize,
+    ) -> bool {
+        if counts.iter().all(|x| *x == 0) {
+            return true;
+        }
+        let Some(free) = taken.points().find(|p| !taken[*p]) else {
+            return false;
+        };
+        for (i, shape) in shapes.iter().enumerate() {
+            if counts[i] == 0 {
+                continue;
+            }
+            for orientation in shape.orientations.iter() {
+                let first = orientation[0];
+                let cells = orientation
+                    .iter()
+                    .map(|p| {
+                        (free.x + p.x)
+                            .checked_sub(first.x)
+                            .map(|x| aoc_2025_grid::Point::new(x, free.y + p.y - first.y))
+                    })
+                    .collect::<Option<Vec<_>>>();
+                let Some(cells) = cells else {
+                    continue;
+                };
+                if cells.iter().any(|p| taken.get(*p) != Some(&false)) {
+                    continue;
+                }
+                cells.iter().for_each(|p| taken[*p] = true);
+                counts[i] -= 1;
+                let found = brute_force(taken, counts, shapes, slack);
+                counts[i] += 1;
+                cells.iter().for_each(|p| taken[*p] = false);
+                if found {
+                    return true;
+                }
+            }
+        }
+        if slack == 0 {
+            return false;
+        }
+        taken[free] = true;
+        let found = brute_force(taken, counts, shapes, slack - 1);
+        taken[free] = false;
+        found
+    }
+
+    /// whether the presents fit both by packing and by plain backtracking
+    fn packs(region: &Region, shapes: &[Shape]) -> Option<(bool, bool)> {
+        let needed = (region.counts.iter().zip(shapes))
+            .map(|(count, shape)| count * shape.area())
+            .sum::<usize>();
+        let slack = (region.width * region.height).checked_sub(needed)?;
+        let (width, height) = (region.width, region.height);
+        let mut taken = Grid::filled(width.min(height), width.max(height), false);
+        let expected = brute_force(&mut taken, &mut region.counts.clone(), shapes, slack);
+        let found = Packing::new(region, shapes, slack).search(0);
+        Some((found, expected))
+    }
+
+    #[test]
+    fn packing() {
+        let shape = |cells: &[(usize, usize)]| {
+            Shape::new(
+                &cells
+                    .iter()
+                    .map(|(x, y)| Point::new(*x, *y))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let ring = [(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (1, 2)];
+        let shapes = [shape(&[(0, 0), (0, 2)]), shape(&ring)];
+        let region = Region {
+            width: 5,
+            height: 4,
+            counts: vec![2, 2],
+        };
+        assert_eq!(packs(&region, &shapes), Some((true, true)));
+        let mut seed = 4u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % n
+        };
+        for _ in 0..1000 {
+            let shapes = (0..2)
+                .map(|_| {
+                    let cells = (0..9)
+                        .filter(|_| random(2) > 0)
+                        .map(|i| (i % 3, i / 3))
+                        .collect::<Vec<_>>();
+                    shape(if cells.is_empty() { &[(0, 0)] } else { &cells })
+                })
+                .collect::<Vec<_>>();
+            // tight regions, where cells left empty are few
+            let counts = (0..2).map(|_| 1 + random(3) as usize).collect::<Vec<_>>();
+            let needed = (counts.iter().zip(&shapes))
+                .map(|(count, shape)| count * shape.area())
+                .sum::<usize>();
+            let width = 3 + random(3) as usize;
+            let region = Region {
+                width,
+                height: (needed + random(3) as usize).div_ceil(width).max(3),
+                counts,
+            };
+            if let Some((found, expected)) = packs(&region, &shapes) {
+                assert_eq!(found, expected, "{region:?} {shapes:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Presents::from_str("0:\n#.\n#o").err(),
+            Some(ParseError::new(2, "o", "`.` or `#`").on_line(3))
+        );
+        assert_eq!(
+            Presents::from_str("0:\n#\n\n2:\n#").err(),
+            Some(ParseError::new(1, "2", "region or shape 1").on_line(4))
+        );
+        assert_eq!(
+            Presents::from_str("0:\n#\n\n4x4 1").err(),
+            Some(ParseError::new(1, "4x4 1", "a region (WxH: counts)").on_line(4))
+        );
+        assert_eq!(
+            Presents::from_str("0:\n#\n\n4x?: 1").err(),
+            Some(ParseError::new(3, "?", "a height").on_line(4))
+        );
+        assert_eq!(
+            Presents::from_str("0:\n#\n\n4x4: 1 2").err(),
+            Some(ParseError::new(6, "1 2", "1 counts of presents").on_line(4))
+        );
+    }
+}